@@ -0,0 +1,61 @@
+//! The checked-in expected answers, `inputs/answers.txt`.
+//!
+//! One answer per line: `<day> <part> <input> <answer>`. Blank lines and lines
+//! starting with `#` are ignored. The answer [`INVALID`] records that the
+//! parser must reject the input.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// Identifies one answer: day, part and input name.
+pub type Key = (u8, u8, String);
+
+pub const FILE_NAME: &str = "answers.txt";
+
+/// The answer for an input that is there to be rejected.
+pub const INVALID: &str = "!invalid";
+
+pub fn load(inputs: &Path) -> Result<BTreeMap<Key, String>, String> {
+    let path = inputs.join(FILE_NAME);
+    let content = match fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(BTreeMap::new()),
+        Err(e) => return Err(format!("could not read '{}': {}", path.display(), e)),
+    };
+    parse(&content).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn parse(content: &str) -> Result<BTreeMap<Key, String>, String> {
+    let mut answers = BTreeMap::new();
+
+    for (idx, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let fields: Vec<&str> = line.splitn(4, char::is_whitespace).collect();
+        let [day, part, input, answer] = fields[..] else {
+            return Err(format!(
+                "line {}: expected '<day> <part> <input> <answer>'",
+                idx + 1
+            ));
+        };
+        let day = day
+            .parse()
+            .map_err(|_| format!("line {}: bad day '{}'", idx + 1, day))?;
+        let part = match part {
+            "1" => 1,
+            "2" => 2,
+            _ => return Err(format!("line {}: bad part '{}'", idx + 1, part)),
+        };
+
+        let key = (day, part, input.to_string());
+        if answers.insert(key, answer.trim().to_string()).is_some() {
+            return Err(format!("line {}: duplicate answer", idx + 1));
+        }
+    }
+
+    Ok(answers)
+}
@@ -0,0 +1,67 @@
+//! Minimal command-line parsing: `aoc [-v|-q] <command> [--flag value]...`.
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+pub struct Args {
+    pub command: String,
+    /// Each `-v` adds one, each `-q` takes one away; `-vv` counts twice.
+    pub verbosity: i32,
+    flags: HashMap<String, String>,
+}
+
+impl Args {
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Args, String> {
+        let mut args = args.into_iter();
+        let mut command = None;
+        let mut flags = HashMap::new();
+        let mut verbosity = 0;
+
+        while let Some(arg) = args.next() {
+            if let Some(name) = arg.strip_prefix("--") {
+                let value = args
+                    .next()
+                    .ok_or_else(|| format!("missing value for --{}", name))?;
+                flags.insert(name.to_string(), value);
+            } else if let Some(letters) = arg.strip_prefix('-') {
+                for letter in letters.chars() {
+                    match letter {
+                        'v' => verbosity += 1,
+                        'q' => verbosity -= 1,
+                        _ => return Err(format!("unknown option '{}'", arg)),
+                    }
+                }
+            } else if command.is_none() {
+                command = Some(arg);
+            } else {
+                return Err(format!("unexpected argument '{}'", arg));
+            }
+        }
+
+        Ok(Args {
+            command: command.ok_or_else(|| "missing command".to_string())?,
+            verbosity,
+            flags,
+        })
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.flags.get(name).map(String::as_str)
+    }
+
+    /// Parse an optional flag, failing if it is present but malformed.
+    pub fn value<T: FromStr>(&self, name: &str) -> Result<Option<T>, String> {
+        match self.get(name) {
+            Some(raw) => raw
+                .parse()
+                .map(Some)
+                .map_err(|_| format!("invalid value '{}' for --{}", raw, name)),
+            None => Ok(None),
+        }
+    }
+
+    pub fn required<T: FromStr>(&self, name: &str) -> Result<T, String> {
+        self.value(name)?
+            .ok_or_else(|| format!("missing required flag --{}", name))
+    }
+}
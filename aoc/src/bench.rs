@@ -0,0 +1,228 @@
+//! `aoc bench`: time parsing, each part and any alternative implementations,
+//! and keep a history of the results per commit in `.aoc/bench_history.json`.
+
+use crate::registry::{self, Day, Solver};
+use libs::input;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+pub struct Options {
+    pub day: Option<u8>,
+    pub input: String,
+    pub warmup: usize,
+    pub runs: usize,
+}
+
+/// One timed target (parse, a part or a variant) as stored in the history file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Record {
+    commit: String,
+    timestamp: u64,
+    day: u8,
+    input: String,
+    target: String,
+    runs: usize,
+    min_ns: u64,
+    median_ns: u64,
+    mean_ns: u64,
+}
+
+impl Record {
+    fn same_target(&self, other: &Record) -> bool {
+        self.day == other.day && self.input == other.input && self.target == other.target
+    }
+}
+
+/// Run `f` `warmup` times untimed, then `runs` times timed.
+fn measure<T>(warmup: usize, runs: usize, mut f: impl FnMut() -> T) -> Vec<Duration> {
+    for _ in 0..warmup {
+        std::hint::black_box(f());
+    }
+    (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            std::hint::black_box(f());
+            start.elapsed()
+        })
+        .collect()
+}
+
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Short hash of HEAD, with `-dirty` appended when there are uncommitted changes.
+fn current_commit() -> String {
+    let Some(hash) = git(&["rev-parse", "--short", "HEAD"]) else {
+        return "unknown".to_string();
+    };
+    match git(&["status", "--porcelain", "--untracked-files=no"]) {
+        Some(status) if !status.is_empty() => format!("{}-dirty", hash),
+        _ => hash,
+    }
+}
+
+fn history_path(inputs: &Path) -> PathBuf {
+    inputs
+        .parent()
+        .unwrap_or(Path::new("."))
+        .join(".aoc")
+        .join("bench_history.json")
+}
+
+fn load_history(path: &Path) -> Result<Vec<Record>, String> {
+    match fs::read_to_string(path) {
+        Ok(content) => serde_json::from_str(&content)
+            .map_err(|e| format!("could not parse '{}': {}", path.display(), e)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(format!("could not read '{}': {}", path.display(), e)),
+    }
+}
+
+fn save_history(path: &Path, history: &[Record]) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|e| format!("could not create '{}': {}", dir.display(), e))?;
+    }
+    let json = serde_json::to_string_pretty(history).map_err(|e| e.to_string())?;
+    fs::write(path, json).map_err(|e| format!("could not write '{}': {}", path.display(), e))
+}
+
+/// The result to compare `record` against: the same target measured on the
+/// parent commit if there is one, otherwise the latest run from any other commit.
+fn baseline<'a>(
+    history: &'a [Record],
+    record: &Record,
+    parent: Option<&str>,
+) -> Option<&'a Record> {
+    let earlier = || {
+        history
+            .iter()
+            .rev()
+            .filter(|old| old.same_target(record) && old.commit != record.commit)
+    };
+    parent
+        .and_then(|parent| earlier().find(|old| old.commit == parent))
+        .or_else(|| earlier().next())
+}
+
+fn record(commit: &str, day: u8, input: &str, target: &str, mut times: Vec<Duration>) -> Record {
+    times.sort();
+    let total: Duration = times.iter().sum();
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|t| t.as_secs())
+        .unwrap_or(0);
+
+    Record {
+        commit: commit.to_string(),
+        timestamp,
+        day,
+        input: input.to_string(),
+        target: target.to_string(),
+        runs: times.len(),
+        min_ns: times[0].as_nanos() as u64,
+        median_ns: times[times.len() / 2].as_nanos() as u64,
+        mean_ns: (total / times.len() as u32).as_nanos() as u64,
+    }
+}
+
+fn bench_day(day: &Day, options: &Options, commit: &str) -> Result<Vec<Record>, String> {
+    let source = input::resolve(day.day, &options.input).map_err(|e| e.to_string())?;
+    let content = input::read(&source).map_err(|e| e.to_string())?;
+    // Parse once up front so a bad input is reported instead of timed
+    let puzzle: Box<dyn Solver> =
+        (day.parse)(&content).map_err(|e| e.in_file(source.to_string()).to_string())?;
+
+    let (warmup, runs) = (options.warmup, options.runs);
+    let mut records = Vec::new();
+
+    let times = measure(warmup, runs, || (day.parse)(&content));
+    records.push(record(commit, day.day, &options.input, "parse", times));
+
+    for part in 1..=2 {
+        let times = measure(warmup, runs, || puzzle.solve(part));
+        let target = format!("part{}", part);
+        records.push(record(commit, day.day, &options.input, &target, times));
+    }
+
+    for (index, name) in puzzle.variant_names().into_iter().enumerate() {
+        let times = measure(warmup, runs, || puzzle.run_variant(index));
+        records.push(record(commit, day.day, &options.input, name, times));
+    }
+
+    Ok(records)
+}
+
+fn print_table(records: &[Record], history: &[Record], parent: Option<&str>) {
+    let ns = |ns: u64| format!("{:.2?}", Duration::from_nanos(ns));
+    println!(
+        "{:>3} {:<8} {:<14} {:>4} {:>12} {:>12} {:>12} {:>12} {:>8}",
+        "day", "input", "target", "runs", "min", "median", "mean", "previous", "change"
+    );
+    for record in records {
+        let (previous, change) = match baseline(history, record, parent) {
+            Some(old) => {
+                let change = (record.median_ns as f64 / old.median_ns.max(1) as f64 - 1.0) * 100.0;
+                (
+                    format!("{} {}", ns(old.median_ns), old.commit),
+                    format!("{:+.1}%", change),
+                )
+            }
+            None => ("-".to_string(), "-".to_string()),
+        };
+        println!(
+            "{:>3} {:<8} {:<14} {:>4} {:>12} {:>12} {:>12} {:>12} {:>8}",
+            record.day,
+            record.input,
+            record.target,
+            record.runs,
+            ns(record.min_ns),
+            ns(record.median_ns),
+            ns(record.mean_ns),
+            previous,
+            change
+        );
+    }
+}
+
+pub fn bench(options: &Options) -> Result<(), String> {
+    if options.runs == 0 {
+        return Err("--runs must be at least 1".to_string());
+    }
+
+    let days: Vec<&Day> = match options.day {
+        Some(day) => vec![registry::find(day).ok_or_else(|| format!("no solver for day {}", day))?],
+        None => registry::DAYS.iter().collect(),
+    };
+
+    let inputs = input::inputs_dir().ok_or("could not find an inputs/ directory")?;
+    let path = history_path(&inputs);
+    let mut history = load_history(&path)?;
+    let commit = current_commit();
+    // With uncommitted changes the previous commit is HEAD itself
+    let parent = if commit.ends_with("-dirty") {
+        git(&["rev-parse", "--short", "HEAD"])
+    } else {
+        git(&["rev-parse", "--short", "HEAD~1"])
+    };
+
+    let mut records = Vec::new();
+    for day in days {
+        records.extend(bench_day(day, options, &commit)?);
+    }
+
+    print_table(&records, &history, parent.as_deref());
+
+    history.extend(records);
+    save_history(&path, &history)?;
+    println!("\nSaved to {}", path.display());
+    Ok(())
+}
@@ -0,0 +1,167 @@
+//! `aoc fetch`: download a day's puzzle input into `inputs/dayNN/`, once.
+//!
+//! The session token comes from `AOC_SESSION` or a `session = ...` line in
+//! `.aoc/config` next to `inputs/`. Inputs already on disk are never fetched
+//! again.
+
+use libs::input;
+use reqwest::blocking::Client;
+use reqwest::header::COOKIE;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const YEAR: u32 = 2024;
+
+/// Identifies this tool to the server, as the site asks automated clients to do.
+const USER_AGENT: &str = concat!(
+    "github.com/codr1/Advent_Of_Code_2024 aoc/",
+    env!("CARGO_PKG_VERSION")
+);
+
+pub struct Options {
+    pub day: u8,
+    pub input: String,
+    /// Overrides the config file and `AOC_BASE_URL`, e.g. for a local mock server.
+    pub base_url: Option<String>,
+}
+
+/// Settings from `.aoc/config`: `key = value` lines, `#` starts a comment.
+#[derive(Debug, Default)]
+struct Config {
+    session: Option<String>,
+    base_url: Option<String>,
+}
+
+fn config_path(inputs: &Path) -> PathBuf {
+    inputs
+        .parent()
+        .unwrap_or(Path::new("."))
+        .join(".aoc")
+        .join("config")
+}
+
+fn load_config(path: &Path) -> Result<Config, String> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Config::default()),
+        Err(e) => return Err(format!("could not read '{}': {}", path.display(), e)),
+    };
+
+    let mut config = Config::default();
+    for (idx, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            return Err(format!(
+                "{}:{}: expected 'key = value'",
+                path.display(),
+                idx + 1
+            ));
+        };
+        let value = Some(value.trim().to_string());
+        match key.trim() {
+            "session" => config.session = value,
+            "base_url" => config.base_url = value,
+            other => {
+                return Err(format!(
+                    "{}:{}: unknown key '{}'",
+                    path.display(),
+                    idx + 1,
+                    other
+                ))
+            }
+        }
+    }
+    Ok(config)
+}
+
+/// First of the flag, the environment variable and the config file value that is set.
+fn setting(flag: Option<&str>, var: &str, config: Option<&str>) -> Option<String> {
+    flag.map(String::from)
+        .or_else(|| env::var(var).ok().filter(|value| !value.is_empty()))
+        .or_else(|| config.map(String::from))
+}
+
+fn download(url: &str, session: &str) -> Result<String, String> {
+    let client = Client::builder()
+        .user_agent(USER_AGENT)
+        .build()
+        .map_err(|e| e.to_string())?;
+    let response = client
+        .get(url)
+        .header(COOKIE, format!("session={}", session))
+        .send()
+        .map_err(|e| format!("could not fetch {}: {}", url, e))?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let hint = match status.as_u16() {
+            400 | 401 | 500 => " (is the session token still valid?)",
+            404 => " (is the puzzle unlocked yet?)",
+            _ => "",
+        };
+        return Err(format!("could not fetch {}: {}{}", url, status, hint));
+    }
+    response
+        .text()
+        .map_err(|e| format!("could not read the response from {}: {}", url, e))
+}
+
+pub fn fetch(options: &Options) -> Result<(), String> {
+    if !(1..=25).contains(&options.day) {
+        return Err(format!("day must be between 1 and 25, got {}", options.day));
+    }
+    // The name becomes a file name, so it must not lead out of the day's directory
+    let name = &options.input;
+    if name.is_empty() || name.contains(['/', '\\']) || name.contains("..") {
+        return Err(format!(
+            "input name must be a plain file name without '/', '\\' or '..', got '{}'",
+            name
+        ));
+    }
+
+    let inputs = input::inputs_dir().ok_or("could not find an inputs/ directory")?;
+    let dir = input::day_dir(&inputs, options.day);
+    let path = dir.join(format!("{}.txt", options.input));
+    if path.exists() {
+        println!(
+            "Day {} input is already cached in {}",
+            options.day,
+            path.display()
+        );
+        return Ok(());
+    }
+
+    let config = load_config(&config_path(&inputs))?;
+    let session = setting(None, "AOC_SESSION", config.session.as_deref())
+        .ok_or("no session token: set AOC_SESSION or add 'session = <token>' to .aoc/config")?;
+    let base_url = setting(
+        options.base_url.as_deref(),
+        "AOC_BASE_URL",
+        config.base_url.as_deref(),
+    )
+    .unwrap_or_else(|| DEFAULT_BASE_URL.to_string());
+
+    let url = format!(
+        "{}/{}/day/{}/input",
+        base_url.trim_end_matches('/'),
+        YEAR,
+        options.day
+    );
+    let content = download(&url, &session)?;
+
+    // Write to a temporary file first so an interrupted fetch never looks cached
+    fs::create_dir_all(&dir).map_err(|e| format!("could not create '{}': {}", dir.display(), e))?;
+    let partial = dir.join(format!(".{}.txt.part", options.input));
+    fs::write(&partial, content)
+        .and_then(|_| fs::rename(&partial, &path))
+        .map_err(|e| format!("could not write '{}': {}", path.display(), e))?;
+
+    println!("Saved day {} input to {}", options.day, path.display());
+    Ok(())
+}
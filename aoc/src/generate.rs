@@ -0,0 +1,438 @@
+//! `aoc gen`: random puzzle inputs of any size, for benchmarking at scale.
+//!
+//! Each generator writes an input that the day's parser accepts and whose
+//! puzzle has an answer: mazes are connected, byte lists do cut the path in
+//! the end, the robots do line up into a picture. Everything is drawn from one
+//! ChaCha RNG seeded with `--seed`, so a seed and size always give the same
+//! input, on any machine.
+
+use libs::{search, Direction, Grid, Point};
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::collections::HashSet;
+use std::fmt::Write;
+use std::ops::RangeInclusive;
+
+pub const DEFAULT_SEED: u64 = 2024;
+
+pub struct Options {
+    pub day: u8,
+    pub seed: u64,
+    /// What this measures depends on the day, see `Generator::size`.
+    pub size: Option<usize>,
+}
+
+struct Generator {
+    day: u8,
+    /// What `--size` counts.
+    size: &'static str,
+    default_size: usize,
+    sizes: RangeInclusive<usize>,
+    generate: fn(&mut ChaCha8Rng, usize) -> String,
+}
+
+static GENERATORS: &[Generator] = &[
+    Generator {
+        day: 1,
+        size: "lines",
+        default_size: 1000,
+        sizes: 1..=usize::MAX,
+        generate: location_lists,
+    },
+    Generator {
+        day: 2,
+        size: "reports",
+        default_size: 1000,
+        sizes: 1..=usize::MAX,
+        generate: reports,
+    },
+    Generator {
+        day: 9,
+        size: "files",
+        default_size: 10000,
+        sizes: 1..=usize::MAX,
+        generate: disk_map,
+    },
+    Generator {
+        day: 12,
+        size: "the side of the garden",
+        default_size: 140,
+        sizes: 1..=usize::MAX,
+        generate: garden,
+    },
+    Generator {
+        day: 14,
+        size: "robots",
+        default_size: 500,
+        // Fewer robots land on distinct tiles by chance long before the picture
+        sizes: 500..=ROOM.0 as usize * ROOM.1 as usize,
+        generate: robots,
+    },
+    Generator {
+        day: 16,
+        size: "the side of the maze",
+        default_size: 141,
+        sizes: 5..=usize::MAX,
+        generate: reindeer_maze,
+    },
+    Generator {
+        day: 18,
+        size: "the side of the memory space",
+        default_size: 71,
+        sizes: 2..=4096,
+        generate: falling_bytes,
+    },
+    Generator {
+        day: 19,
+        size: "designs",
+        default_size: 400,
+        sizes: 1..=usize::MAX,
+        generate: towels,
+    },
+    Generator {
+        day: 20,
+        size: "the side of the racetrack",
+        default_size: 141,
+        sizes: 5..=usize::MAX,
+        generate: racetrack,
+    },
+];
+
+/// Day 1: two columns of five-digit location IDs. About a third of the
+/// right-hand IDs also appear on the left, so the similarity is not zero.
+fn location_lists(rng: &mut ChaCha8Rng, lines: usize) -> String {
+    let left: Vec<u32> = (0..lines).map(|_| rng.gen_range(10000..100000)).collect();
+    let mut out = String::new();
+    for &id in &left {
+        let other = match rng.gen_ratio(1, 3) {
+            true => *left.choose(rng).unwrap(),
+            false => rng.gen_range(10000..100000),
+        };
+        writeln!(out, "{}   {}", id, other).unwrap();
+    }
+    out
+}
+
+/// Day 2: reports of 5 to 8 levels that rise or fall by 1 to 3 at a time,
+/// except that one in four has a level knocked out of line and one in four
+/// is random.
+fn reports(rng: &mut ChaCha8Rng, count: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..count {
+        let len = rng.gen_range(5..=8);
+        let rising = rng.gen_bool(0.5);
+        let mut level: i32 = if rising {
+            rng.gen_range(1..=60)
+        } else {
+            rng.gen_range(40..=99)
+        };
+        let mut levels = Vec::with_capacity(len);
+        for _ in 0..len {
+            levels.push(level);
+            let step = rng.gen_range(1..=3);
+            level += if rising { step } else { -step };
+        }
+
+        match rng.gen_range(0..4) {
+            0 => {
+                let at = rng.gen_range(0..len);
+                levels[at] = rng.gen_range(1..=99);
+            }
+            1 => levels.iter_mut().for_each(|l| *l = rng.gen_range(1..=99)),
+            _ => {}
+        }
+
+        let line: Vec<String> = levels.iter().map(i32::to_string).collect();
+        writeln!(out, "{}", line.join(" ")).unwrap();
+    }
+    out
+}
+
+/// Day 9: files of 1 to 9 blocks with gaps of 0 to 9 between them.
+fn disk_map(rng: &mut ChaCha8Rng, files: usize) -> String {
+    let mut out = String::with_capacity(files * 2);
+    for file in 0..files {
+        if file > 0 {
+            out.push(char::from(b'0' + rng.gen_range(0..=9)));
+        }
+        out.push(char::from(b'0' + rng.gen_range(1..=9)));
+    }
+    out.push('\n');
+    out
+}
+
+/// Day 12: plots grown outwards from random seeds, one letter per seed.
+/// Neighbouring seeds can share a letter, which merges their regions the
+/// way the real inputs do.
+fn garden(rng: &mut ChaCha8Rng, side: usize) -> String {
+    let mut grid = Grid::new(side, side, '.');
+    let points: Vec<Point> = grid.points().collect();
+    let seeds = (side * side / 40).max(1);
+
+    let mut frontier = Vec::new();
+    for &p in points.choose_multiple(rng, seeds) {
+        grid[p] = char::from(b'A' + rng.gen_range(0..26));
+        frontier.push(p);
+    }
+
+    // Growing from a random frontier plot keeps the regions ragged
+    while !frontier.is_empty() {
+        let p = frontier.swap_remove(rng.gen_range(0..frontier.len()));
+        let plant = grid[p];
+        let open: Vec<Point> = grid.neighbours4(p).filter(|&n| grid[n] == '.').collect();
+        for n in open {
+            grid[n] = plant;
+            frontier.push(n);
+        }
+    }
+    grid.to_string()
+}
+
+/// The room the real day 14 robots patrol.
+const ROOM: (i64, i64) = (101, 103);
+
+/// Day 14: robots that all stand on distinct tiles, drawing a tree, at one
+/// random step, and are wound back from there to their starting positions.
+fn robots(rng: &mut ChaCha8Rng, count: usize) -> String {
+    let (width, height) = ROOM;
+    let picture_at = rng.gen_range(1..width * height);
+
+    // A triangle for the tree and a short trunk, using about half the robots
+    let rows = ((count / 2) as f64).sqrt().clamp(1.0, 40.0) as i64;
+    let left = rng.gen_range(0..=width - (2 * rows - 1));
+    let top = rng.gen_range(0..=height - (rows + 2));
+    let mut tiles = Vec::new();
+    for row in 0..rows {
+        for x in rows - 1 - row..rows + row {
+            tiles.push((left + x, top + row));
+        }
+    }
+    for row in rows..rows + 2 {
+        for x in rows - 2..=rows {
+            tiles.push((left + x, top + row));
+        }
+    }
+
+    let mut taken: HashSet<(i64, i64)> = tiles.iter().copied().collect();
+    while tiles.len() < count {
+        let tile = (rng.gen_range(0..width), rng.gen_range(0..height));
+        if taken.insert(tile) {
+            tiles.push(tile);
+        }
+    }
+
+    let mut out = String::new();
+    for (x, y) in tiles.into_iter().take(count) {
+        let vx = rng.gen_range(-(width - 1)..width);
+        let vy = rng.gen_range(-(height - 1)..height);
+        let x0 = (x - vx * picture_at).rem_euclid(width);
+        let y0 = (y - vy * picture_at).rem_euclid(height);
+        writeln!(out, "p={},{} v={},{}", x0, y0, vx, vy).unwrap();
+    }
+    out
+}
+
+/// A perfect maze: passages on the odd rows and columns, walls all round,
+/// and exactly one way between any two passages. `side` must be odd.
+fn perfect_maze(rng: &mut ChaCha8Rng, side: usize) -> Grid<char> {
+    let mut grid = Grid::new(side, side, '#');
+    let last = side as i32 - 2;
+    let start = Point::new(1, last);
+    grid[start] = '.';
+
+    // Depth-first, carving through to a random unvisited cell two steps away
+    let mut stack = vec![start];
+    while let Some(&cell) = stack.last() {
+        let mut directions = Direction::ALL;
+        directions.shuffle(rng);
+        let next = directions.into_iter().find(|&d| {
+            let n = cell + d.delta() * 2;
+            (1..=last).contains(&n.x) && (1..=last).contains(&n.y) && grid[n] == '#'
+        });
+        match next {
+            Some(d) => {
+                let n = cell + d.delta() * 2;
+                grid[cell.step(d)] = '.';
+                grid[n] = '.';
+                stack.push(n);
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    }
+    grid
+}
+
+/// Mazes need walls on odd and passages on even positions, so round even sides up.
+fn odd(side: usize) -> usize {
+    side | 1
+}
+
+/// Day 16: a perfect maze with one wall in ten knocked through so there are
+/// loops and several equally short ways, S bottom left and E top right.
+fn reindeer_maze(rng: &mut ChaCha8Rng, side: usize) -> String {
+    let side = odd(side);
+    let mut grid = perfect_maze(rng, side);
+    let last = side as i32 - 2;
+
+    for p in grid.points().collect::<Vec<_>>() {
+        let inner = (1..=last).contains(&p.x) && (1..=last).contains(&p.y);
+        // Walls between two passages sit where exactly one coordinate is even
+        if inner && (p.x + p.y) % 2 == 1 && grid[p] == '#' && rng.gen_ratio(1, 10) {
+            grid[p] = '.';
+        }
+    }
+    grid[Point::new(1, last)] = 'S';
+    grid[Point::new(last, 1)] = 'E';
+    grid.to_string()
+}
+
+/// Day 20: a single track with no branches, the path from S to E through a
+/// perfect maze with every dead end walled up again.
+fn racetrack(rng: &mut ChaCha8Rng, side: usize) -> String {
+    let side = odd(side);
+    let maze = perfect_maze(rng, side);
+    let last = side as i32 - 2;
+    let (start, end) = (Point::new(1, last), Point::new(last, 1));
+
+    let open = |&p: &Point| maze.neighbours4(p).filter(|&n| maze[n] == '.');
+    let path = search::bfs(start, open, |&p| p == end)
+        .path(&end)
+        .expect("a perfect maze connects every passage");
+
+    let mut grid = Grid::new(side, side, '#');
+    for p in path {
+        grid[p] = '.';
+    }
+    grid[start] = 'S';
+    grid[end] = 'E';
+    grid.to_string()
+}
+
+/// Whether the first `fallen` bytes leave a way from corner to corner.
+fn passable(side: usize, bytes: &[Point], fallen: usize) -> bool {
+    let mut grid = Grid::new(side, side, '.');
+    for &p in &bytes[..fallen] {
+        grid[p] = '#';
+    }
+    let goal = Point::new(side as i32 - 1, side as i32 - 1);
+    let open = |&p: &Point| grid.neighbours4(p).filter(|&n| grid[n] == '.');
+    search::bfs(Point::new(0, 0), open, |&p| p == goal)
+        .goal_cost()
+        .is_some()
+}
+
+/// Day 18: a `space:` line with the side and the bytes that fall before
+/// part one, then seven in ten of the positions in the space in random
+/// order, never the two corners. The list always goes on past the byte that
+/// cuts the way off, and part one stops before it.
+fn falling_bytes(rng: &mut ChaCha8Rng, side: usize) -> String {
+    let last = side as i32 - 1;
+    let mut bytes: Vec<Point> = (0..=last)
+        .flat_map(|y| (0..=last).map(move |x| Point::new(x, y)))
+        .filter(|&p| p != Point::new(0, 0) && p != Point::new(last, last))
+        .collect();
+    bytes.shuffle(rng);
+
+    // Every position filled cuts the way, so search for the first byte that does
+    let (mut open, mut cut) = (0, bytes.len());
+    while open + 1 < cut {
+        let mid = (open + cut) / 2;
+        if passable(side, &bytes, mid) {
+            open = mid;
+        } else {
+            cut = mid;
+        }
+    }
+    bytes.truncate(cut.max(side * side * 7 / 10));
+    let fallen = (side * side / 5).min(cut - 1);
+
+    let mut out = format!("space: {} {}\n", side, fallen);
+    for p in bytes {
+        writeln!(out, "{},{}", p.x, p.y).unwrap();
+    }
+    out
+}
+
+const STRIPES: &[u8] = b"wubrg";
+
+fn stripes(rng: &mut ChaCha8Rng, len: usize) -> String {
+    (0..len)
+        .map(|_| char::from(*STRIPES.choose(rng).unwrap()))
+        .collect()
+}
+
+/// Day 19: 450 towels of 1 to 8 stripes, none of which ends in one chosen
+/// colour, and designs of which half are made from the towels and half are
+/// random but end in that colour, so cannot be made.
+fn towels(rng: &mut ChaCha8Rng, count: usize) -> String {
+    let missing = char::from(*STRIPES.choose(rng).unwrap());
+    let mut patterns = Vec::new();
+    let mut seen = HashSet::new();
+    while patterns.len() < 450 {
+        let len = rng.gen_range(1..=8);
+        let towel = stripes(rng, len);
+        if !towel.ends_with(missing) && seen.insert(towel.clone()) {
+            patterns.push(towel);
+        }
+    }
+
+    let mut out = patterns.join(", ");
+    out.push_str("\n\n");
+    for _ in 0..count {
+        let len = rng.gen_range(20..=60);
+        let design = if rng.gen_bool(0.5) {
+            let mut design = String::new();
+            while design.len() < len {
+                design.push_str(patterns.choose(rng).unwrap());
+            }
+            design
+        } else {
+            stripes(rng, len - 1) + &missing.to_string()
+        };
+        writeln!(out, "{}", design).unwrap();
+    }
+    out
+}
+
+fn days_with_generators() -> String {
+    let days: Vec<String> = GENERATORS.iter().map(|g| g.day.to_string()).collect();
+    days.join(", ")
+}
+
+/// The input for `options.day`, and a line saying what it holds.
+pub fn generate(options: &Options) -> Result<(String, String), String> {
+    let generator = GENERATORS
+        .iter()
+        .find(|g| g.day == options.day)
+        .ok_or_else(|| {
+            format!(
+                "no generator for day {}; there are generators for days {}",
+                options.day,
+                days_with_generators()
+            )
+        })?;
+
+    let size = options.size.unwrap_or(generator.default_size);
+    if !generator.sizes.contains(&size) {
+        let (min, max) = (generator.sizes.start(), generator.sizes.end());
+        let range = match *max {
+            usize::MAX => format!("at least {}", min),
+            _ => format!("between {} and {}", min, max),
+        };
+        return Err(format!(
+            "day {} --size is {}, which must be {}, got {}",
+            options.day, generator.size, range, size
+        ));
+    }
+
+    let mut rng = ChaCha8Rng::seed_from_u64(options.seed);
+    let input = (generator.generate)(&mut rng, size);
+    let summary = format!(
+        "Day {} input with {} {} (seed {})",
+        options.day, generator.size, size, options.seed
+    );
+    Ok((input, summary))
+}
@@ -0,0 +1,274 @@
+mod answers;
+mod args;
+mod bench;
+mod fetch;
+mod generate;
+mod new;
+mod registry;
+mod verify;
+
+use args::Args;
+use libs::log::{self, Level};
+use libs::record::{self, Format, Palette, Recorder};
+use libs::viz::{self, Mode};
+use libs::{debug, info, input, metrics};
+use serde_json::json;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+const USAGE: &str = "\
+usage: aoc [-v|-vv|-q] run --day N [--part 1|2] [--input NAME|PATH|-] [--format text|json]
+                           [--viz off|text|term] [--fps N]
+                           [--record PATH [--record-format gif|apng|png|ppm]
+                            [--cell PIXELS] [--delay MS] [--palette dark|light|FILE]]
+       aoc verify [--day N]
+       aoc bench [--day N] [--input NAME|PATH] [--warmup N] [--runs N]
+       aoc fetch --day N [--input NAME] [--base-url URL]
+       aoc new --day N [--name TYPE]
+       aoc gen --day N [--seed S] [--size N]
+
+--input takes a file path, `-` for stdin, or a name looked up as
+inputs/dayNN/NAME.txt (default: data).
+
+-v shows solver diagnostics, -vv full traces and map drawings (on stderr);
+-q prints nothing but the bare answers. --format json prints one JSON object
+with each part's answer, timing and solver metrics.
+
+--viz animates the days that draw their maps: `term` redraws in place (space
+pauses, n steps, +/- change speed, q stops), `text` prints every frame to
+stderr. -vv implies --viz text. --fps limits the frame rate.
+
+--record saves the same frames as an animation: a .gif or .png (APNG) file,
+or with --record-format png|ppm a directory of numbered images. Each cell is
+PIXELS square (default 4) and each frame stays up MS milliseconds (default 50).
+
+fetch downloads inputs/dayNN/NAME.txt unless it already exists, using the
+session token from AOC_SESSION or 'session = <token>' in .aoc/config.
+
+new creates the dayN crate with a Solution skeleton named TYPE (default
+Puzzle) and registers it with the workspace and this runner.
+
+gen prints a random input for day 1, 2, 9, 12, 14, 16, 18, 19 or 20. --size
+is the number of lines, reports, files, robots or designs, or the side of the
+grid; the same --seed and --size always give the same input.";
+
+/// The recorder asked for with `--record`, if any.
+fn recorder(args: &Args) -> Result<Option<Recorder>, String> {
+    let Some(path) = args.get("record").map(PathBuf::from) else {
+        return Ok(None);
+    };
+    let format = match args.get("record-format") {
+        Some(name) => Format::from_name(name).ok_or_else(|| {
+            format!(
+                "record format must be gif, apng, png or ppm, got '{}'",
+                name
+            )
+        })?,
+        None => Format::from_path(&path).ok_or_else(|| {
+            format!(
+                "can't tell the format of '{}': name a .gif or .png file or pass --record-format",
+                path.display()
+            )
+        })?,
+    };
+    let palette = match args.get("palette") {
+        None => Palette::default(),
+        Some(name) => match Palette::named(name) {
+            Some(palette) => palette,
+            None => {
+                let text = std::fs::read_to_string(name)
+                    .map_err(|e| format!("could not read palette '{}': {}", name, e))?;
+                Palette::parse(&text).map_err(|e| format!("{}: {}", name, e))?
+            }
+        },
+    };
+
+    Ok(Some(Recorder::new(record::Options {
+        format,
+        path,
+        cell_size: args.value("cell")?.unwrap_or(4),
+        delay: Duration::from_millis(args.value("delay")?.unwrap_or(50)),
+        palette,
+    })))
+}
+
+fn run(args: &Args) -> Result<ExitCode, String> {
+    let day: u8 = args.required("day")?;
+    let solver = registry::find(day).ok_or_else(|| format!("no solver for day {}", day))?;
+
+    let parts = match args.value::<u8>("part")? {
+        Some(part @ 1..=2) => vec![part],
+        Some(part) => return Err(format!("part must be 1 or 2, got {}", part)),
+        None => vec![1, 2],
+    };
+
+    let json = match args.get("format").unwrap_or("text") {
+        "text" => false,
+        "json" => true,
+        other => return Err(format!("format must be text or json, got '{}'", other)),
+    };
+
+    // Full traces have always included the map drawings
+    let viz_mode = match args.get("viz") {
+        None if log::enabled(Level::Trace) => Mode::Text,
+        None | Some("off") => Mode::Off,
+        Some("text") => Mode::Text,
+        Some("term") => Mode::Terminal,
+        Some(other) => return Err(format!("viz must be off, text or term, got '{}'", other)),
+    };
+    viz::configure(viz_mode, args.value("fps")?);
+    if let Some(recorder) = recorder(args)? {
+        viz::record(recorder);
+    }
+
+    let spec = args.get("input").unwrap_or(input::DEFAULT_INPUT);
+    let source = input::resolve(day, spec).map_err(|e| e.to_string())?;
+    let input = input::read(&source).map_err(|e| e.to_string())?;
+
+    let start = Instant::now();
+    let puzzle = match (solver.parse)(&input) {
+        Ok(puzzle) => puzzle,
+        Err(e) => {
+            // A bad input is not a usage mistake, so skip the usage text
+            eprintln!("error: {}", e.in_file(source.to_string()));
+            return Ok(ExitCode::FAILURE);
+        }
+    };
+    let parse_time = start.elapsed();
+    debug!("Day {} parsed ({:.2?})", day, parse_time);
+
+    // Metrics can be costly to gather (day 20 lists every shortcut), so only
+    // collect them when they will be shown
+    let collect_metrics = json || log::enabled(Level::Debug);
+
+    let mut results = Vec::new();
+    for part in parts {
+        if collect_metrics {
+            metrics::start();
+        }
+        let start = Instant::now();
+        let answer = puzzle.solve(part);
+        let elapsed = start.elapsed();
+        viz::finish();
+        let part_metrics = metrics::finish();
+
+        if json {
+            results.push(json!({
+                "part": part,
+                "answer": answer,
+                "elapsed_ns": elapsed.as_nanos() as u64,
+                "metrics": part_metrics,
+            }));
+        } else if log::enabled(Level::Info) {
+            println!("Day {} part {}: {} ({:.2?})", day, part, answer, elapsed);
+            for (name, value) in &part_metrics {
+                debug!("  {}: {}", name, value);
+            }
+        } else {
+            println!("{}", answer);
+        }
+    }
+
+    if let Some(saved) = viz::save_recording() {
+        let saved = saved.map_err(|e| format!("could not save the recording: {}", e))?;
+        info!("Recorded {}", saved);
+    }
+
+    if json {
+        let report = json!({
+            "day": day,
+            "input": spec,
+            "parse_ns": parse_time.as_nanos() as u64,
+            "parts": results,
+        });
+        println!("{}", report);
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn bench(args: &Args) -> Result<ExitCode, String> {
+    let options = bench::Options {
+        day: args.value("day")?,
+        input: args
+            .get("input")
+            .unwrap_or(input::DEFAULT_INPUT)
+            .to_string(),
+        warmup: args.value("warmup")?.unwrap_or(1),
+        runs: args.value("runs")?.unwrap_or(5),
+    };
+    bench::bench(&options)?;
+    Ok(ExitCode::SUCCESS)
+}
+
+fn verify(args: &Args) -> Result<ExitCode, String> {
+    let passed = verify::verify(args.value("day")?)?;
+    Ok(if passed {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
+
+fn fetch(args: &Args) -> Result<ExitCode, String> {
+    let options = fetch::Options {
+        day: args.required("day")?,
+        input: args
+            .get("input")
+            .unwrap_or(input::DEFAULT_INPUT)
+            .to_string(),
+        base_url: args.get("base-url").map(String::from),
+    };
+    fetch::fetch(&options)?;
+    Ok(ExitCode::SUCCESS)
+}
+
+fn new(args: &Args) -> Result<ExitCode, String> {
+    let options = new::Options {
+        day: args.required("day")?,
+        name: args.get("name").unwrap_or(new::DEFAULT_NAME).to_string(),
+    };
+    new::new(&options)?;
+    Ok(ExitCode::SUCCESS)
+}
+
+fn generate(args: &Args) -> Result<ExitCode, String> {
+    let options = generate::Options {
+        day: args.required("day")?,
+        seed: args.value("seed")?.unwrap_or(generate::DEFAULT_SEED),
+        size: args.value("size")?,
+    };
+    let (input, summary) = generate::generate(&options)?;
+    io::stdout()
+        .lock()
+        .write_all(input.as_bytes())
+        .map_err(|e| format!("could not write the input: {}", e))?;
+    info!("{}", summary);
+    Ok(ExitCode::SUCCESS)
+}
+
+fn main() -> ExitCode {
+    let result = Args::parse(std::env::args().skip(1)).and_then(|args| {
+        log::set_level(Level::from_verbosity(args.verbosity));
+        match args.command.as_str() {
+            "run" => run(&args),
+            "verify" => verify(&args),
+            "bench" => bench(&args),
+            "fetch" => fetch(&args),
+            "new" => new(&args),
+            "gen" => generate(&args),
+            other => Err(format!("unknown command '{}'", other)),
+        }
+    });
+
+    match result {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            eprintln!("{}", USAGE);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,261 @@
+//! `aoc new`: start a day from the same template every time.
+//!
+//! Creates the `dayN` crate with a `Solution` skeleton and a sample test,
+//! an empty `inputs/dayNN/` for `aoc fetch` to fill, and registers the crate
+//! in the workspace, in `aoc`'s dependencies and in the dispatch table. When
+//! `fuzz/` is there, the parser also gets a fuzz target.
+//! Everything is worked out before the first file is written, so a failure
+//! leaves the tree as it was.
+
+use libs::input;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_NAME: &str = "Puzzle";
+
+pub struct Options {
+    pub day: u8,
+    /// Name of the parsed-input type that implements `Solution`.
+    pub name: String,
+}
+
+const CARGO_TOML: &str = "\
+[package]
+name = \"day{day}\"
+version.workspace = true
+edition.workspace = true
+
+[dependencies]
+libs.workspace = true
+";
+
+const LIB_RS: &str = r#"use libs::{debug, ParseError, Solution};
+
+/// The parsed puzzle input.
+pub struct {name} {
+    lines: Vec<String>,
+}
+
+impl Solution for {name} {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let lines: Vec<String> = input.lines().map(String::from).collect();
+        if lines.is_empty() {
+            return Err(ParseError::end_of_input(1, "the puzzle input"));
+        }
+        Ok({name} { lines })
+    }
+
+    fn part1(&self) -> usize {
+        debug!("{} lines", self.lines.len());
+        0
+    }
+
+    fn part2(&self) -> usize {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The example from the puzzle text.
+    const SAMPLE: &str = "\
+";
+
+    #[test]
+    #[ignore = "fill in SAMPLE and the expected answers"]
+    fn sample() {
+        let puzzle = {name}::parse(SAMPLE).unwrap();
+        assert_eq!(puzzle.part1(), 0);
+        assert_eq!(puzzle.part2(), 0);
+    }
+}
+"#;
+
+const FUZZ_TARGET: &str = "\
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use libs::Solution;
+
+fuzz_target!(|input: &str| {
+    let _ = day{day}::{name}::parse(input);
+});
+";
+
+const FUZZ_BIN: &str = "
+[[bin]]
+name = \"day{day:02}\"
+path = \"fuzz_targets/day{day:02}.rs\"
+test = false
+doc = false
+bench = false
+";
+
+/// A file to write: where, and what goes in it.
+struct Change {
+    path: PathBuf,
+    content: String,
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("could not read '{}': {}", path.display(), e))
+}
+
+fn fill(template: &str, day: u8, name: &str) -> String {
+    template
+        .replace("{day}", &day.to_string())
+        .replace("{day:02}", &format!("{:02}", day))
+        .replace("{name}", name)
+}
+
+fn is_type_name(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_uppercase())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Add `"dayN",` as the last entry of the workspace `members` list.
+fn add_member(manifest: &str, day: u8) -> Result<String, String> {
+    let list = manifest
+        .find("members = [")
+        .ok_or("no 'members = [' list in the workspace Cargo.toml")?;
+    let close = list
+        + manifest[list..]
+            .find("\n]")
+            .ok_or("the workspace members list is not closed by ']' on its own line")?;
+    Ok(format!(
+        "{}\n    \"day{}\",{}",
+        &manifest[..close],
+        day,
+        &manifest[close..]
+    ))
+}
+
+/// Add `dayN = { path = "../dayN" }` after the other days a manifest depends on.
+fn add_dependency(manifest: &str, day: u8) -> String {
+    let line = format!("day{0} = {{ path = \"../day{0}\" }}\n", day);
+    let after_last_day = manifest
+        .match_indices("\nday")
+        .last()
+        .and_then(|(at, _)| manifest[at + 1..].find('\n').map(|end| at + 1 + end + 1));
+
+    match after_last_day {
+        Some(at) => format!("{}{}{}", &manifest[..at], line, &manifest[at..]),
+        None if manifest.ends_with('\n') => format!("{}{}", manifest, line),
+        None => format!("{}\n{}", manifest, line),
+    }
+}
+
+/// Add `day!(N, dayN::Name),` as the last entry of `DAYS`.
+fn add_to_registry(registry: &str, day: u8, name: &str) -> Result<String, String> {
+    let table = registry
+        .find("pub static DAYS")
+        .ok_or("no DAYS table in registry.rs")?;
+    let close = table
+        + registry[table..]
+            .find("\n];")
+            .ok_or("the DAYS table is not closed by '];' on its own line")?;
+    Ok(format!(
+        "{}\n    day!({1}, day{1}::{2}),{3}",
+        &registry[..close],
+        day,
+        name,
+        &registry[close..]
+    ))
+}
+
+pub fn new(options: &Options) -> Result<(), String> {
+    let Options { day, name } = options;
+    let day = *day;
+    if !(1..=25).contains(&day) {
+        return Err(format!("day must be between 1 and 25, got {}", day));
+    }
+    if !is_type_name(name) {
+        return Err(format!(
+            "name must be a type name such as {}, got '{}'",
+            DEFAULT_NAME, name
+        ));
+    }
+
+    let inputs = input::inputs_dir().ok_or("could not find an inputs/ directory")?;
+    let root = inputs.parent().unwrap_or(Path::new("."));
+    let krate = root.join(format!("day{}", day));
+    if krate.exists() {
+        return Err(format!("'{}' already exists", krate.display()));
+    }
+
+    let workspace = root.join("Cargo.toml");
+    let runner = root.join("aoc").join("Cargo.toml");
+    let registry = root.join("aoc").join("src").join("registry.rs");
+    let registry_text = read(&registry)?;
+    if registry_text.contains(&format!("day!({},", day)) {
+        return Err(format!(
+            "day {} is already registered in '{}'",
+            day,
+            registry.display()
+        ));
+    }
+
+    let mut changes = vec![
+        Change {
+            path: krate.join("Cargo.toml"),
+            content: fill(CARGO_TOML, day, name),
+        },
+        Change {
+            path: krate.join("src").join("lib.rs"),
+            content: fill(LIB_RS, day, name),
+        },
+        // Git keeps no empty directories
+        Change {
+            path: input::day_dir(&inputs, day).join(".gitkeep"),
+            content: String::new(),
+        },
+        Change {
+            content: add_member(&read(&workspace)?, day)?,
+            path: workspace,
+        },
+        Change {
+            content: add_dependency(&read(&runner)?, day),
+            path: runner,
+        },
+        Change {
+            content: add_to_registry(&registry_text, day, name)?,
+            path: registry,
+        },
+    ];
+
+    let fuzz = root.join("fuzz");
+    if fuzz.join("Cargo.toml").exists() {
+        let manifest = fuzz.join("Cargo.toml");
+        let content = add_dependency(&read(&manifest)?, day) + &fill(FUZZ_BIN, day, name);
+        changes.push(Change {
+            path: fuzz.join("fuzz_targets").join(format!("day{:02}.rs", day)),
+            content: fill(FUZZ_TARGET, day, name),
+        });
+        changes.push(Change {
+            path: manifest,
+            content,
+        });
+    }
+
+    for change in &changes {
+        if let Some(dir) = change.path.parent() {
+            fs::create_dir_all(dir)
+                .map_err(|e| format!("could not create '{}': {}", dir.display(), e))?;
+        }
+        fs::write(&change.path, &change.content)
+            .map_err(|e| format!("could not write '{}': {}", change.path.display(), e))?;
+    }
+
+    println!("Created {} for day {}", krate.display(), day);
+    println!(
+        "Next: `aoc fetch --day {0}`, fill in the sample test in day{0}/src/lib.rs, \
+         then `aoc run --day {0}`",
+        day
+    );
+    Ok(())
+}
@@ -0,0 +1,77 @@
+//! Dispatch table from a day number to that day's solver.
+
+use libs::{ParseError, Solution};
+
+/// A parsed puzzle with its concrete answer types erased, so one table can hold every day.
+pub trait Solver {
+    /// Answer `part` (1 or 2) as display text.
+    fn solve(&self, part: u8) -> String;
+
+    /// Names of the alternative implementations this day offers.
+    fn variant_names(&self) -> Vec<&'static str>;
+
+    /// Run the variant at `index` in `variant_names`.
+    fn run_variant(&self, index: usize) -> String;
+}
+
+impl<S: Solution> Solver for S {
+    fn solve(&self, part: u8) -> String {
+        match part {
+            1 => self.part1().to_string(),
+            _ => self.part2().to_string(),
+        }
+    }
+
+    fn variant_names(&self) -> Vec<&'static str> {
+        S::variants().iter().map(|(name, _)| *name).collect()
+    }
+
+    fn run_variant(&self, index: usize) -> String {
+        (S::variants()[index].1)(self)
+    }
+}
+
+fn parse_boxed<S: Solution + 'static>(input: &str) -> Result<Box<dyn Solver>, ParseError> {
+    S::parse(input).map(|solution| Box::new(solution) as Box<dyn Solver>)
+}
+
+pub struct Day {
+    pub day: u8,
+    pub parse: fn(&str) -> Result<Box<dyn Solver>, ParseError>,
+}
+
+macro_rules! day {
+    ($day:expr, $solution:path) => {
+        Day {
+            day: $day,
+            parse: parse_boxed::<$solution>,
+        }
+    };
+}
+
+pub static DAYS: &[Day] = &[
+    day!(1, day1::Lists),
+    day!(2, day2::Reports),
+    day!(3, day3::Program),
+    day!(4, day4::WordSearch),
+    day!(5, day5::PrintQueue),
+    day!(6, day6::Lab),
+    day!(7, day7::Equations),
+    day!(8, day8::Antennas),
+    day!(9, day9::DiskMap),
+    day!(10, day10::TopoMap),
+    day!(11, day11::Stones),
+    day!(12, day12::Garden),
+    day!(13, day13::Arcade),
+    day!(14, day14::Bathroom),
+    day!(15, day15::Warehouse),
+    day!(16, day16::Maze),
+    day!(17, day17::Computer),
+    day!(18, day18::MemorySpace),
+    day!(19, day19::Towels),
+    day!(20, day20::Racetrack),
+];
+
+pub fn find(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|entry| entry.day == day)
+}
@@ -0,0 +1,193 @@
+//! `aoc verify`: run every solver on every stored input and compare with
+//! the expected answers.
+
+use crate::answers::{self, Key};
+use crate::registry::{self, Day};
+use libs::input;
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+enum Status {
+    Ok,
+    Mismatch,
+    /// The solver ran but there is no expected answer on record.
+    Missing,
+    /// There is an expected answer but the input file is gone.
+    NoInput,
+    /// The input was rejected by the parser.
+    Invalid(String),
+    /// The input was rejected, as `answers::INVALID` says it should be.
+    Rejected(String),
+    Panicked(String),
+}
+
+struct Row {
+    day: u8,
+    part: u8,
+    input: String,
+    expected: Option<String>,
+    got: Option<String>,
+    elapsed: Option<Duration>,
+    status: Status,
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(msg) = payload.downcast_ref::<&str>() {
+        msg.to_string()
+    } else if let Some(msg) = payload.downcast_ref::<String>() {
+        msg.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// Run both parts of `day` on one input, turning panics into rows.
+/// Expected answers are removed from `expected` as they are checked, so
+/// whatever is left afterwards has no input to run against.
+fn check_input(
+    day: &Day,
+    name: &str,
+    content: &str,
+    expected: &mut BTreeMap<Key, String>,
+) -> Vec<Row> {
+    let parsed = panic::catch_unwind(|| (day.parse)(content));
+
+    let mut rows = Vec::new();
+    for part in 1..=2 {
+        let want = expected.remove(&(day.day, part, name.to_string()));
+        let mut row = Row {
+            day: day.day,
+            part,
+            input: name.to_string(),
+            expected: want,
+            got: None,
+            elapsed: None,
+            status: Status::Missing,
+        };
+
+        match &parsed {
+            Ok(Ok(puzzle)) => {
+                let start = Instant::now();
+                let result = panic::catch_unwind(AssertUnwindSafe(|| puzzle.solve(part)));
+                row.elapsed = Some(start.elapsed());
+                match result {
+                    Ok(answer) => {
+                        row.status = match &row.expected {
+                            Some(want) if *want == answer => Status::Ok,
+                            Some(_) => Status::Mismatch,
+                            None => Status::Missing,
+                        };
+                        row.got = Some(answer);
+                    }
+                    Err(payload) => row.status = Status::Panicked(panic_message(payload)),
+                }
+            }
+            Ok(Err(error)) if row.expected.as_deref() == Some(answers::INVALID) => {
+                row.status = Status::Rejected(error.to_string());
+            }
+            Ok(Err(error)) => row.status = Status::Invalid(error.to_string()),
+            Err(_) => row.status = Status::Panicked("panicked while parsing".to_string()),
+        }
+        rows.push(row);
+    }
+    rows
+}
+
+fn print_table(rows: &[Row]) {
+    println!(
+        "{:>3} {:>4} {:<8} {:<9} {:>12} {:<20} {:<20}",
+        "day", "part", "input", "status", "time", "expected", "got"
+    );
+    for row in rows {
+        let status = match &row.status {
+            Status::Ok => "ok".to_string(),
+            Status::Mismatch => "MISMATCH".to_string(),
+            Status::Missing => "missing".to_string(),
+            Status::NoInput => "no input".to_string(),
+            Status::Invalid(_) => "INVALID".to_string(),
+            Status::Rejected(_) => "rejected".to_string(),
+            Status::Panicked(_) => "PANICKED".to_string(),
+        };
+        let time = row
+            .elapsed
+            .map(|t| format!("{:.2?}", t))
+            .unwrap_or_else(|| "-".to_string());
+        let got = match &row.status {
+            Status::Invalid(msg) | Status::Rejected(msg) | Status::Panicked(msg) => msg.clone(),
+            _ => row.got.clone().unwrap_or_else(|| "-".to_string()),
+        };
+        println!(
+            "{:>3} {:>4} {:<8} {:<9} {:>12} {:<20} {:<20}",
+            row.day,
+            row.part,
+            row.input,
+            status,
+            time,
+            row.expected.as_deref().unwrap_or("-"),
+            got
+        );
+    }
+}
+
+/// Verify `only_day`, or every registered day. Returns whether everything passed.
+pub fn verify(only_day: Option<u8>) -> Result<bool, String> {
+    let inputs = input::inputs_dir().ok_or("could not find an inputs/ directory")?;
+    let mut expected = answers::load(&inputs)?;
+    if let Some(day) = only_day {
+        expected.retain(|(d, _, _), _| *d == day);
+    }
+
+    let days: Vec<&Day> = match only_day {
+        Some(day) => vec![registry::find(day).ok_or_else(|| format!("no solver for day {}", day))?],
+        None => registry::DAYS.iter().collect(),
+    };
+
+    // Solvers that panic should show up in the table, not spray the terminal
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let mut rows = Vec::new();
+    for day in days {
+        for name in input::available(&inputs, day.day) {
+            let path = input::day_dir(&inputs, day.day).join(format!("{}.txt", name));
+            let content = input::read(&input::Source::File(path)).map_err(|e| e.to_string())?;
+            rows.extend(check_input(day, &name, &content, &mut expected));
+        }
+    }
+
+    panic::set_hook(default_hook);
+
+    for ((day, part, input), answer) in expected {
+        rows.push(Row {
+            day,
+            part,
+            input,
+            expected: Some(answer),
+            got: None,
+            elapsed: None,
+            status: Status::NoInput,
+        });
+    }
+    rows.sort_by(|a, b| (a.day, &a.input, a.part).cmp(&(b.day, &b.input, b.part)));
+
+    print_table(&rows);
+
+    let count = |pred: fn(&Status) -> bool| rows.iter().filter(|row| pred(&row.status)).count();
+    let ok = count(|s| matches!(s, Status::Ok | Status::Rejected(_)));
+    let failed = count(|s| {
+        matches!(
+            s,
+            Status::Mismatch | Status::Invalid(_) | Status::Panicked(_)
+        )
+    });
+    let missing = count(|s| matches!(s, Status::Missing | Status::NoInput));
+    let total: Duration = rows.iter().filter_map(|row| row.elapsed).sum();
+    println!(
+        "\n{} ok, {} failed, {} missing ({:.2?} total)",
+        ok, failed, missing, total
+    );
+
+    Ok(failed == 0)
+}
@@ -0,0 +1,140 @@
+//! `aoc fetch` against a throwaway local HTTP server.
+
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::process::{Command, Output};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+const INPUT: &str = "3   4\n4   3\n";
+
+/// Serve `INPUT` to every request and keep the request heads for inspection.
+fn mock_server() -> (String, Arc<Mutex<Vec<String>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(Mutex::new(Vec::new()));
+
+    let seen = Arc::clone(&requests);
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut head = String::new();
+            let mut reader = BufReader::new(&stream);
+            loop {
+                let mut line = String::new();
+                if reader.read_line(&mut line).unwrap() == 0 || line == "\r\n" {
+                    break;
+                }
+                head.push_str(&line);
+            }
+            seen.lock().unwrap().push(head.to_lowercase());
+
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                INPUT.len(),
+                INPUT
+            );
+            stream.write_all(response.as_bytes()).unwrap();
+        }
+    });
+
+    (url, requests)
+}
+
+/// A fresh workspace-like directory with an empty `inputs/`.
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("inputs")).unwrap();
+    dir
+}
+
+fn aoc_fetch(dir: &PathBuf, session: Option<&str>, base_url: &str, extra: &[&str]) -> Output {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_aoc"));
+    command
+        .args(["fetch", "--day", "1", "--base-url", base_url])
+        .args(extra)
+        .current_dir(dir)
+        .env_remove("AOC_SESSION")
+        .env_remove("AOC_BASE_URL");
+    if let Some(session) = session {
+        command.env("AOC_SESSION", session);
+    }
+    command.output().unwrap()
+}
+
+#[test]
+fn fetches_once_and_then_uses_the_cache() {
+    let (url, requests) = mock_server();
+    let dir = scratch_dir("cache");
+
+    let output = aoc_fetch(&dir, Some("secret"), &url, &[]);
+    assert!(output.status.success(), "{:?}", output);
+    let cached = dir.join("inputs/day01/data.txt");
+    assert_eq!(fs::read_to_string(&cached).unwrap(), INPUT);
+
+    {
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("get /2024/day/1/input "));
+        assert!(requests[0].contains("cookie: session=secret"));
+        assert!(requests[0].contains("user-agent: github.com/codr1/advent_of_code_2024"));
+    }
+
+    let output = aoc_fetch(&dir, Some("secret"), &url, &[]);
+    assert!(output.status.success(), "{:?}", output);
+    assert_eq!(requests.lock().unwrap().len(), 1);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn reads_the_session_from_the_config_file() {
+    let (url, requests) = mock_server();
+    let dir = scratch_dir("config");
+    fs::create_dir_all(dir.join(".aoc")).unwrap();
+    fs::write(dir.join(".aoc/config"), "# token\nsession = from-file\n").unwrap();
+
+    let output = aoc_fetch(&dir, None, &url, &[]);
+    assert!(output.status.success(), "{:?}", output);
+    assert!(requests.lock().unwrap()[0].contains("cookie: session=from-file"));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn refuses_to_fetch_without_a_session() {
+    let (url, requests) = mock_server();
+    let dir = scratch_dir("no-session");
+
+    let output = aoc_fetch(&dir, None, &url, &[]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("no session token"));
+    assert!(requests.lock().unwrap().is_empty());
+    assert!(!dir.join("inputs/day01/data.txt").exists());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn keeps_input_names_inside_the_day_directory() {
+    let (url, requests) = mock_server();
+    let dir = scratch_dir("names");
+
+    for name in ["../../escaped", "a/b", "a\\b", "..", ""] {
+        let output = aoc_fetch(&dir, Some("secret"), &url, &["--input", name]);
+        assert!(!output.status.success(), "{:?}", name);
+        assert!(
+            String::from_utf8_lossy(&output.stderr).contains("plain file name"),
+            "{:?}: {:?}",
+            name,
+            output
+        );
+    }
+    assert!(requests.lock().unwrap().is_empty());
+    assert!(!dir.join("escaped.txt").exists());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,76 @@
+//! `aoc gen` inputs fed straight back into `aoc run`.
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn aoc(args: &[&str], stdin: &[u8]) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(stdin).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn gen(day: &str, size: &str, seed: &str) -> Vec<u8> {
+    let output = aoc(
+        &["-q", "gen", "--day", day, "--size", size, "--seed", seed],
+        b"",
+    );
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    output.stdout
+}
+
+#[test]
+fn every_generated_input_is_solved() {
+    // Small sizes, apart from day 14 which needs enough robots for its picture
+    let cases = [
+        ("1", "100"),
+        ("2", "100"),
+        ("9", "100"),
+        ("12", "30"),
+        ("14", "1000"),
+        ("16", "21"),
+        ("18", "12"),
+        ("19", "20"),
+        ("20", "21"),
+    ];
+    for (day, size) in cases {
+        let input = gen(day, size, "1");
+        let output = aoc(&["-q", "run", "--day", day, "--input", "-"], &input);
+        assert!(
+            output.status.success(),
+            "day {}: {}",
+            day,
+            String::from_utf8_lossy(&output.stderr)
+        );
+        let answers = String::from_utf8(output.stdout).unwrap();
+        assert_eq!(answers.lines().count(), 2, "day {}: {}", day, answers);
+    }
+}
+
+#[test]
+fn the_seed_decides_the_input() {
+    assert_eq!(gen("16", "31", "7"), gen("16", "31", "7"));
+    assert_ne!(gen("16", "31", "7"), gen("16", "31", "8"));
+}
+
+#[test]
+fn unknown_days_and_sizes_are_rejected() {
+    for args in [
+        &["gen", "--day", "3"][..],
+        &["gen", "--day", "18", "--size", "1"],
+        &["gen", "--day", "14", "--size", "20"],
+    ] {
+        let output = aoc(args, b"");
+        assert!(!output.status.success(), "{:?}", args);
+        assert!(output.stdout.is_empty(), "{:?}", args);
+    }
+}
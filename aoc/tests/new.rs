@@ -0,0 +1,95 @@
+//! `aoc new` against a scratch copy of the workspace's manifests and registry.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+/// The files `aoc new` edits, copied from this workspace, plus an empty `inputs/`.
+fn scratch_workspace(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc-new-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("inputs")).unwrap();
+    fs::create_dir_all(dir.join("aoc/src")).unwrap();
+    fs::create_dir_all(dir.join("fuzz")).unwrap();
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    for file in [
+        "Cargo.toml",
+        "aoc/Cargo.toml",
+        "aoc/src/registry.rs",
+        "fuzz/Cargo.toml",
+    ] {
+        fs::copy(root.join(file), dir.join(file)).unwrap();
+    }
+    dir
+}
+
+fn aoc_new(dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .arg("new")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .unwrap()
+}
+
+fn read(dir: &Path, file: &str) -> String {
+    fs::read_to_string(dir.join(file)).unwrap()
+}
+
+#[test]
+fn creates_and_registers_a_day() {
+    let dir = scratch_workspace("create");
+
+    let output = aoc_new(&dir, &["--day", "21", "--name", "Keypad"]);
+    assert!(output.status.success(), "{:?}", output);
+
+    let manifest = read(&dir, "day21/Cargo.toml");
+    assert!(manifest.contains("name = \"day21\""));
+    assert!(manifest.contains("edition.workspace = true"));
+    assert!(manifest.contains("libs.workspace = true"));
+
+    let lib = read(&dir, "day21/src/lib.rs");
+    assert!(lib.contains("impl Solution for Keypad"));
+    assert!(lib.contains("fn sample()"));
+    assert!(dir.join("inputs/day21").is_dir());
+
+    assert!(read(&dir, "Cargo.toml").contains("    \"day20\",\n    \"day21\",\n]"));
+    assert!(read(&dir, "aoc/Cargo.toml")
+        .contains("day20 = { path = \"../day20\" }\nday21 = { path = \"../day21\" }\n"));
+    assert!(read(&dir, "aoc/src/registry.rs").contains("    day!(21, day21::Keypad),\n];"));
+
+    assert!(read(&dir, "fuzz/fuzz_targets/day21.rs").contains("day21::Keypad::parse(input)"));
+    let fuzz = read(&dir, "fuzz/Cargo.toml");
+    assert!(fuzz.contains("day20 = { path = \"../day20\" }\nday21 = { path = \"../day21\" }\n"));
+    assert!(fuzz.ends_with("name = \"day21\"\npath = \"fuzz_targets/day21.rs\"\ntest = false\ndoc = false\nbench = false\n"));
+
+    // A second run must not touch the day it already made
+    let output = aoc_new(&dir, &["--day", "21"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("already exists"));
+    assert!(read(&dir, "day21/src/lib.rs").contains("Keypad"));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn rejects_bad_arguments_without_writing_anything() {
+    let dir = scratch_workspace("reject");
+    let registry = read(&dir, "aoc/src/registry.rs");
+
+    for args in [
+        ["--day", "26", "--name", "Keypad"],
+        ["--day", "21", "--name", "keypad"],
+        ["--day", "20", "--name", "Keypad"],
+    ] {
+        let output = aoc_new(&dir, &args);
+        assert!(!output.status.success(), "{:?}", args);
+    }
+
+    assert!(!dir.join("day21").exists());
+    assert!(!dir.join("day26").exists());
+    assert_eq!(read(&dir, "aoc/src/registry.rs"), registry);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,221 @@
+//! Every day's parser on mangled copies of the stored inputs: malformed input
+//! must come back as a `ParseError`, never as a panic or an overflow, and
+//! input that parses must not make part 1 panic either.
+//!
+//! This is a cheap, deterministic stand-in for the libFuzzer targets under
+//! `fuzz/`, so that `cargo test` on stable catches regressions too.
+
+use libs::Solution;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+
+const MUTATIONS: usize = 300;
+
+/// Only the head of each input is mangled, which keeps the run short.
+const MAX_SEED_LEN: usize = 1500;
+
+/// Bytes that tripped parsers up before or sit on a boundary they check.
+const INTERESTING: &[&[u8]] = &[
+    b"\r",
+    b"\n",
+    b"\t",
+    b" ",
+    b"-",
+    b"+",
+    b",",
+    b":",
+    b"0",
+    b"9",
+    b"\0",
+    b"\x7f",
+    "é".as_bytes(),
+    b"99999999999999999999",
+    b"-2147483649",
+    b"#",
+    b".",
+    b"^",
+    b"S",
+    b"E",
+    b"@",
+    b"O",
+    b"[",
+    b"]",
+    b"p=",
+    b"v=",
+    b"Button A: X+",
+    b"Register A: ",
+    b"Program: ",
+    b"mul(",
+    b"do()",
+    b"don't()",
+    b"|",
+];
+
+macro_rules! parsers {
+    ($($day:literal => $parsed:ty),* $(,)?) => {
+        &[$(($day, |input: &str| {
+            let _ = <$parsed>::parse(input);
+        })),*]
+    };
+}
+
+/// Like `parsers!`, but part 1 also runs on whatever parses.
+macro_rules! solvers {
+    ($($day:literal => $parsed:ty),* $(,)?) => {
+        &[$(($day, |input: &str| {
+            if let Ok(puzzle) = <$parsed>::parse(input) {
+                let _ = puzzle.part1();
+            }
+        })),*]
+    };
+}
+
+/// Parses its input and throws the result away.
+type Parse = fn(&str);
+
+static PARSERS: &[(u8, Parse)] = parsers![
+    1 => day1::Lists,
+    2 => day2::Reports,
+    3 => day3::Program,
+    4 => day4::WordSearch,
+    5 => day5::PrintQueue,
+    6 => day6::Lab,
+    7 => day7::Equations,
+    8 => day8::Antennas,
+    9 => day9::DiskMap,
+    10 => day10::TopoMap,
+    11 => day11::Stones,
+    12 => day12::Garden,
+    13 => day13::Arcade,
+    14 => day14::Bathroom,
+    15 => day15::Warehouse,
+    16 => day16::Maze,
+    17 => day17::Computer,
+    18 => day18::MemorySpace,
+    19 => day19::Towels,
+    20 => day20::Racetrack,
+];
+
+/// Days whose part 1 does arithmetic straight on numbers from the input,
+/// which the parser alone cannot show to be safe.
+static SOLVERS: &[(u8, Parse)] = solvers![
+    1 => day1::Lists,
+    2 => day2::Reports,
+    3 => day3::Program,
+    5 => day5::PrintQueue,
+    7 => day7::Equations,
+    9 => day9::DiskMap,
+    11 => day11::Stones,
+    13 => day13::Arcade,
+    14 => day14::Bathroom,
+    17 => day17::Computer,
+];
+
+/// xorshift64*, so every run mangles the inputs the same way.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n.max(1) as u64) as usize
+    }
+}
+
+fn mutate(seed: &[u8], rng: &mut Rng) -> Vec<u8> {
+    let mut bytes = seed.to_vec();
+    for _ in 0..1 + rng.below(4) {
+        let at = rng.below(bytes.len() + 1);
+        let token = INTERESTING[rng.below(INTERESTING.len())];
+        let (end, replacement): (usize, &[u8]) = match rng.below(5) {
+            // Overwrite, insert, delete a short run, or cut the rest off
+            0 => (at + token.len(), token),
+            1 => (at, token),
+            2 => (at + 1 + rng.below(20), &[]),
+            3 => (bytes.len(), &[]),
+            _ => {
+                // Flip one byte to anything at all
+                if let Some(b) = bytes.get_mut(at) {
+                    *b = rng.next() as u8;
+                }
+                continue;
+            }
+        };
+        bytes.splice(at..end.min(bytes.len()), replacement.iter().copied());
+    }
+    bytes
+}
+
+fn seeds(day: u8) -> Vec<Vec<u8>> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../inputs")
+        .join(format!("day{:02}", day));
+    let mut seeds = vec![Vec::new(), b"\n".to_vec()];
+    for entry in fs::read_dir(dir).into_iter().flatten().flatten() {
+        let mut bytes = fs::read(entry.path()).unwrap();
+        if bytes.len() > MAX_SEED_LEN {
+            let cut = bytes[..MAX_SEED_LEN]
+                .iter()
+                .rposition(|&b| b == b'\n')
+                .map_or(MAX_SEED_LEN, |nl| nl + 1);
+            bytes.truncate(cut);
+        }
+        seeds.push(bytes);
+    }
+    seeds
+}
+
+/// Run `check` on every seed of `day` and its mutations, and describe the
+/// first panic for each seed.
+fn panics(day: u8, check: Parse) -> Vec<String> {
+    let mut failures = Vec::new();
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15 ^ day as u64);
+    for seed in seeds(day) {
+        for round in 0..=MUTATIONS {
+            let bytes = if round == 0 {
+                seed.clone()
+            } else {
+                mutate(&seed, &mut rng)
+            };
+            let input = String::from_utf8_lossy(&bytes);
+            if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(|| check(&input))) {
+                let message = payload
+                    .downcast_ref::<String>()
+                    .cloned()
+                    .or_else(|| payload.downcast_ref::<&str>().map(|s| s.to_string()))
+                    .unwrap_or_default();
+                failures.push(format!("day {}: {} on {:?}", day, message, input));
+                break;
+            }
+        }
+    }
+    failures
+}
+
+fn assert_no_panics(checks: &[(u8, Parse)]) {
+    // The failures are collected below; the default hook would only add noise
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let failures: Vec<String> = checks
+        .iter()
+        .flat_map(|&(day, check)| panics(day, check))
+        .collect();
+    panic::set_hook(hook);
+    assert!(failures.is_empty(), "{}", failures.join("\n\n"));
+}
+
+#[test]
+fn malformed_inputs_are_errors_not_panics() {
+    assert_no_panics(PARSERS);
+}
+
+#[test]
+fn inputs_that_parse_do_not_panic_in_part_1() {
+    assert_no_panics(SOLVERS);
+}
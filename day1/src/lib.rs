@@ -0,0 +1,342 @@
+use libs::error::{self, ParseError};
+use libs::log::{self, Level};
+use libs::{debug, metrics, trace, Solution};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt::Write;
+
+const EXPECTED: &str = "at least two numbers separated by whitespace";
+
+/// Read the same number of numbers from every line, two or more, and return
+/// each column sorted. Columns may be separated by any run of spaces or tabs,
+/// lines may end in CRLF, and blank lines are skipped.
+fn parse_columns(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    let mut columns: Vec<Vec<i64>> = Vec::new();
+
+    for (idx, line) in input.lines().enumerate() {
+        let line_no = idx + 1;
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        if tokens.is_empty() {
+            continue;
+        }
+
+        // The first line decides how many columns there are
+        if columns.is_empty() {
+            if tokens.len() < 2 {
+                return Err(ParseError::new(
+                    line_no,
+                    line.trim_end().len() + 1,
+                    EXPECTED,
+                    "end of line",
+                ));
+            }
+            columns = vec![Vec::new(); tokens.len()];
+        }
+        if tokens.len() < columns.len() {
+            return Err(ParseError::new(
+                line_no,
+                line.trim_end().len() + 1,
+                format!("{} numbers separated by whitespace", columns.len()),
+                "end of line",
+            ));
+        }
+        if let Some(extra) = tokens.get(columns.len()) {
+            return Err(ParseError::at(line_no, line, extra, "end of line"));
+        }
+
+        for (column, token) in columns.iter_mut().zip(tokens) {
+            column.push(error::number(line_no, line, token)?);
+        }
+    }
+
+    if columns.is_empty() {
+        return Err(ParseError::end_of_input(1, EXPECTED));
+    }
+
+    for column in &mut columns {
+        column.sort_unstable();
+    }
+    Ok(columns)
+}
+
+/// The location-ID lists, one per column, each sorted ascending. The puzzle
+/// has two; the answers compare the first two and the rest only show up in
+/// the matrices and statistics.
+pub struct Lists {
+    columns: Vec<Vec<i64>>,
+}
+
+impl Lists {
+    pub fn columns(&self) -> &[Vec<i64>] {
+        &self.columns
+    }
+
+    pub fn column_stats(&self) -> Vec<ColumnStats> {
+        self.columns.iter().map(|c| ColumnStats::of(c)).collect()
+    }
+}
+
+/// The matrices and statistics are only worth computing when someone sees them.
+fn reporting() -> bool {
+    metrics::enabled() || log::enabled(Level::Debug)
+}
+
+impl Solution for Lists {
+    type Answer1 = i128;
+    type Answer2 = i128;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Lists {
+            columns: parse_columns(input)?,
+        })
+    }
+
+    fn part1(&self) -> i128 {
+        if !reporting() {
+            return total_distance(&self.columns[0], &self.columns[1]);
+        }
+
+        let stats = self.column_stats();
+        let distances = distance_matrix(&self.columns);
+        debug!("{}", stats_table(&stats));
+        debug!("{}", matrix_table("distance", &distances));
+        metrics::record("columns", self.columns.len());
+        metrics::record("column_stats", &stats);
+        metrics::record("distances", &distances);
+        distances[0][1]
+    }
+
+    fn part2(&self) -> i128 {
+        if !reporting() {
+            return similarity(&self.columns[0], &self.columns[1]);
+        }
+
+        let similarities = similarity_matrix(&self.columns);
+        debug!("{}", matrix_table("similarity", &similarities));
+        metrics::record("similarities", &similarities);
+        similarities[0][1]
+    }
+}
+
+/// Sum of the distances between the lists' numbers paired up in order.
+/// Both lists must already be sorted ascending. The sum is an `i128` as a
+/// single distance can already be as large as `u64::MAX`.
+pub fn total_distance(list1: &[i64], list2: &[i64]) -> i128 {
+    let mut sum = 0;
+    for (&a, &b) in list1.iter().zip(list2) {
+        sum += i128::from(a.abs_diff(b));
+        trace!("{} {} {} {}", a, b, a.abs_diff(b), sum);
+    }
+    sum
+}
+
+fn counts(list: &[i64]) -> HashMap<i64, i64> {
+    let mut counts = HashMap::new();
+    for &num in list {
+        *counts.entry(num).or_default() += 1;
+    }
+    counts
+}
+
+fn similarity_to(list: &[i64], counts: &HashMap<i64, i64>) -> i128 {
+    list.iter()
+        .map(|&num| i128::from(num) * i128::from(counts.get(&num).copied().unwrap_or(0)))
+        .sum()
+}
+
+/// Every number in `list1` times how often it appears in `list2`, summed.
+/// Neither list needs to be sorted.
+pub fn similarity(list1: &[i64], list2: &[i64]) -> i128 {
+    let counts = counts(list2);
+    trace!("{} distinct numbers in list2", counts.len());
+    similarity_to(list1, &counts)
+}
+
+/// `total_distance` between every pair of sorted columns. The matrix is
+/// symmetric with zeros on the diagonal.
+pub fn distance_matrix(columns: &[Vec<i64>]) -> Vec<Vec<i128>> {
+    let mut matrix = vec![vec![0; columns.len()]; columns.len()];
+    for i in 0..columns.len() {
+        for j in i + 1..columns.len() {
+            let distance = total_distance(&columns[i], &columns[j]);
+            matrix[i][j] = distance;
+            matrix[j][i] = distance;
+        }
+    }
+    matrix
+}
+
+/// `similarity` between every pair of columns. Each number counts once per
+/// pair of occurrences, one in each column, so the matrix is symmetric; the
+/// diagonal weighs every number by the square of its count.
+pub fn similarity_matrix(columns: &[Vec<i64>]) -> Vec<Vec<i128>> {
+    let counts: Vec<HashMap<i64, i64>> = columns.iter().map(|c| counts(c)).collect();
+    let mut matrix = vec![vec![0; columns.len()]; columns.len()];
+    for i in 0..columns.len() {
+        for j in i..columns.len() {
+            let similarity = similarity_to(&columns[i], &counts[j]);
+            matrix[i][j] = similarity;
+            matrix[j][i] = similarity;
+        }
+    }
+    matrix
+}
+
+/// Summary of one column.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ColumnStats {
+    pub min: i64,
+    pub max: i64,
+    /// The middle number, or the mean of the two middle ones.
+    pub median: f64,
+    /// How many numbers repeat one earlier in the column.
+    pub duplicates: usize,
+}
+
+impl ColumnStats {
+    /// Statistics of a sorted, non-empty column.
+    pub fn of(sorted: &[i64]) -> Self {
+        let mid = sorted.len() / 2;
+        let median = match sorted.len() % 2 {
+            1 => sorted[mid] as f64,
+            _ => (sorted[mid - 1] as f64 + sorted[mid] as f64) / 2.0,
+        };
+        ColumnStats {
+            min: sorted[0],
+            max: sorted[sorted.len() - 1],
+            median,
+            duplicates: sorted.windows(2).filter(|w| w[0] == w[1]).count(),
+        }
+    }
+}
+
+fn stats_table(stats: &[ColumnStats]) -> String {
+    let mut table = format!(
+        "{:>6} {:>12} {:>12} {:>14} {:>10}",
+        "column", "min", "max", "median", "duplicates"
+    );
+    for (i, s) in stats.iter().enumerate() {
+        write!(
+            table,
+            "\n{:>6} {:>12} {:>12} {:>14} {:>10}",
+            i + 1,
+            s.min,
+            s.max,
+            s.median,
+            s.duplicates
+        )
+        .unwrap();
+    }
+    table
+}
+
+fn matrix_table(title: &str, matrix: &[Vec<i128>]) -> String {
+    let mut table = format!("{:>10}", title);
+    for j in 0..matrix.len() {
+        write!(table, " {:>14}", j + 1).unwrap();
+    }
+    for (i, row) in matrix.iter().enumerate() {
+        write!(table, "\n{:>10}", i + 1).unwrap();
+        for value in row {
+            write!(table, " {:>14}", value).unwrap();
+        }
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+";
+
+    #[test]
+    fn sample() {
+        let lists = Lists::parse(SAMPLE).unwrap();
+        assert_eq!(lists.part1(), 11);
+        assert_eq!(lists.part2(), 31);
+    }
+
+    #[test]
+    fn any_whitespace_negatives_and_crlf() {
+        let input = "3\t4\r\n-4 \t 3\r\n\r\n  2   -4  \r\n";
+        let lists = Lists::parse(input).unwrap();
+        assert_eq!(lists.columns(), [[-4, 2, 3], [-4, 3, 4]]);
+        assert_eq!(lists.part1(), 2);
+        assert_eq!(lists.part2(), -1);
+    }
+
+    #[test]
+    fn rejects_missing_and_extra_columns() {
+        let missing = Lists::parse("1 2\n3\n").err().unwrap();
+        assert_eq!(
+            missing.to_string(),
+            "2:2: expected 2 numbers separated by whitespace, found end of line"
+        );
+        let extra = Lists::parse("1 2\n1 2 3\n").err().unwrap();
+        assert_eq!(extra.to_string(), "2:5: expected end of line, found '3'");
+        let single = Lists::parse("1\n").err().unwrap();
+        assert_eq!(
+            single.to_string(),
+            format!("1:2: expected {}, found end of line", EXPECTED)
+        );
+        assert!(Lists::parse("1 x\n").is_err());
+        assert!(Lists::parse(" \n\n").is_err());
+    }
+
+    #[test]
+    fn matrices_and_stats_over_every_column() {
+        let input = "3 4 1\n4 3 1\n2 5 2\n1 3 3\n3 9 3\n3 3 8\n";
+        let lists = Lists::parse(input).unwrap();
+        assert_eq!(lists.columns().len(), 3);
+
+        let distances = distance_matrix(lists.columns());
+        assert_eq!(distances, [[0, 11, 6], [11, 0, 9], [6, 9, 0]]);
+        let similarities = similarity_matrix(lists.columns());
+        assert_eq!(similarities, [[34, 31, 22], [31, 45, 18], [22, 18, 26]]);
+        assert_eq!(similarities[0][1], lists.part2());
+
+        let stats = lists.column_stats();
+        let first = ColumnStats {
+            min: 1,
+            max: 4,
+            median: 3.0,
+            duplicates: 2,
+        };
+        assert_eq!(stats[0], first);
+        assert_eq!(stats[2].median, 2.5);
+        assert_eq!(stats[2].duplicates, 2);
+    }
+
+    #[test]
+    fn sums_past_i64() {
+        let lists = Lists::parse("-9223372036854775808 9223372036854775807\n").unwrap();
+        assert_eq!(lists.part1(), i128::from(u64::MAX));
+        assert_eq!(lists.part2(), 0);
+
+        let max = i64::MAX;
+        let input = format!("{max} {max}\n{max} {max}\n-5 5\n");
+        let lists = Lists::parse(&input).unwrap();
+        assert_eq!(lists.part1(), 10);
+        assert_eq!(lists.part2(), 4 * i128::from(max));
+    }
+
+    #[test]
+    fn a_million_lines() {
+        let lines = 1_000_000;
+        let input: String = (0..lines)
+            .map(|i| format!("{} {}\n", i, (i * 7) % lines))
+            .collect();
+        let lists = Lists::parse(&input).unwrap();
+        // Both columns hold 0..lines once each, as 7 is coprime to a million
+        assert_eq!(lists.part1(), 0);
+        assert_eq!(lists.part2(), (lines - 1) * lines / 2);
+    }
+}
@@ -0,0 +1,121 @@
+use libs::{trace, Grid, ParseError, Point, Solution, Variant};
+
+fn search_path_with_visit(
+    map: &Grid<u32>,
+    p: Point,
+    current_value: u32,
+    visited: &mut Grid<bool>,
+) -> u32 {
+    if visited[p] {
+        return 0;
+    }
+    visited[p] = true;
+
+    // If we found a 9, increment score and return
+    if map[p] == 9 {
+        return 1;
+    }
+
+    let mut score = 0;
+    for next in map.neighbours4(p) {
+        // Check if the adjacent tile is exactly one bigger
+        if map[next] == current_value + 1 {
+            score += search_path_with_visit(map, next, map[next], visited);
+        }
+    }
+
+    score
+}
+
+fn search_path_no_visit(map: &Grid<u32>, p: Point, current_value: u32) -> u32 {
+    // If we found a 9, increment score and return
+    if map[p] == 9 {
+        return 1;
+    }
+
+    let mut score = 0;
+    for next in map.neighbours4(p) {
+        // Check if the adjacent tile is exactly one bigger
+        if map[next] == current_value + 1 {
+            score += search_path_no_visit(map, next, map[next]);
+        }
+    }
+
+    score
+}
+
+fn parse_map(input: &str) -> Result<Grid<u32>, ParseError> {
+    Grid::parse(input, "a digit", |c| c.to_digit(10))
+}
+
+pub struct TopoMap(Grid<u32>);
+
+impl TopoMap {
+    fn trail_count(&self, visit: bool) -> u32 {
+        let map = &self.0;
+        let mut total_score = 0;
+
+        // Trails start at height 0
+        for head in map.find_all(&0) {
+            let (label, score) = if visit {
+                let mut visited = Grid::new(map.width(), map.height(), false);
+                (
+                    "with_visit",
+                    search_path_with_visit(map, head, 0, &mut visited),
+                )
+            } else {
+                ("no_visit", search_path_no_visit(map, head, 0))
+            };
+            trace!(
+                "Trailhead at ({}, {}) scores: {}={}",
+                head.x,
+                head.y,
+                label,
+                score
+            );
+            total_score += score;
+        }
+
+        total_score
+    }
+}
+
+impl Solution for TopoMap {
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        parse_map(input).map(TopoMap)
+    }
+
+    /// Distinct summits reachable from each trailhead.
+    fn part1(&self) -> u32 {
+        self.trail_count(true)
+    }
+
+    /// Distinct hiking trails from each trailhead.
+    fn part2(&self) -> u32 {
+        self.trail_count(false)
+    }
+
+    fn variants() -> Vec<Variant<Self>> {
+        vec![
+            ("with_visit", |map| map.trail_count(true).to_string()),
+            ("no_visit", |map| map.trail_count(false).to_string()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = include_str!("../../inputs/day10/data.txt");
+
+    #[test]
+    fn sample() {
+        let map = TopoMap::parse(SAMPLE).unwrap();
+        assert_eq!(map.part1(), 36);
+        assert_eq!(map.part2(), 81);
+    }
+}
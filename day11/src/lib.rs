@@ -0,0 +1,276 @@
+use libs::error::ParseError;
+use libs::{debug, trace, Solution, Variant};
+use std::collections::HashMap;
+
+const LOOKUP_DEPTH: usize = 25; // How many steps to pre-compute
+const LOOKUP_MAX: usize = 10000; // Maximum number to precompute
+
+/// `table[n][d]` is how many stones `n` becomes after `d` blinks, for every
+/// `n` up to `max` and `d` below `depth`.
+///
+/// Only the counts are kept: holding every intermediate stone list for all
+/// numbers up to `LOOKUP_MAX` needs several gigabytes.
+fn precompute_digits(max: usize, depth: usize) -> Vec<Vec<usize>> {
+    let mut table = vec![vec![0; depth]; max + 1];
+
+    for (num, row) in table.iter_mut().enumerate() {
+        let mut numbers = vec![num as u128];
+        row[0] = 1;
+
+        // Compute each depth
+        for blink in 0..depth - 1 {
+            let mut new_numbers = Vec::with_capacity(numbers.len() * 2);
+            for &n in &numbers {
+                if n == 0 {
+                    new_numbers.push(1);
+                } else {
+                    let digits = count_digits(n);
+                    if digits.is_multiple_of(2) {
+                        let (front, back) = get_digit_parts(n, digits);
+                        new_numbers.push(front);
+                        new_numbers.push(back);
+                    } else {
+                        new_numbers.push(n * 2024);
+                    }
+                }
+            }
+            numbers = new_numbers;
+            row[blink + 1] = numbers.len();
+        }
+    }
+    table
+}
+
+fn process_stone_depth_with_lookup(
+    stone: u128,
+    depth: usize,
+    max_depth: usize,
+    lookup: &[Vec<usize>],
+) -> usize {
+    if depth == max_depth {
+        return 1;
+    }
+
+    // Single step from max_depth
+    if depth == max_depth - 1 {
+        let digits = count_digits(stone);
+        if stone == 0 {
+            return 1;
+        } else if digits.is_multiple_of(2) {
+            return 2;
+        } else {
+            return 1;
+        }
+    }
+
+    // Use lookup table when possible
+    let row = usize::try_from(stone).ok().and_then(|n| lookup.get(n));
+    if let Some(&count) = row.and_then(|row| row.get(max_depth - depth)) {
+        return count;
+    }
+
+    // Regular processing
+    let digits = count_digits(stone);
+    if stone == 0 {
+        process_stone_depth_with_lookup(1, depth + 1, max_depth, lookup)
+    } else if digits.is_multiple_of(2) {
+        let (front, back) = get_digit_parts(stone, digits);
+        process_stone_depth_with_lookup(front, depth + 1, max_depth, lookup)
+            + process_stone_depth_with_lookup(back, depth + 1, max_depth, lookup)
+    } else {
+        process_stone_depth_with_lookup(stone * 2024, depth + 1, max_depth, lookup)
+    }
+}
+
+fn count_digits(n: u128) -> usize {
+    if n == 0 {
+        return 1;
+    };
+    let mut n = n;
+    let mut count = 0;
+    while n > 0 {
+        n /= 10;
+        count += 1;
+    }
+
+    count
+}
+
+fn get_digit_parts(mut n: u128, digits: usize) -> (u128, u128) {
+    let mut idx = 0;
+    let mut back = 0;
+    while idx < digits / 2 {
+        back += (n % 10) * 10_u128.pow(idx as u32);
+        n /= 10;
+        idx += 1;
+    }
+
+    (n, back)
+}
+
+/// Stones are read as `u64` but blink as `u128`: an odd-digit stone gains at
+/// most four digits when multiplied and splits within two multiplications,
+/// so no stone grows past 28 digits.
+fn parse_stones(input: &str) -> Result<Vec<u128>, ParseError> {
+    let mut stones = Vec::new();
+    for (idx, line) in input.lines().enumerate() {
+        for token in line.split_whitespace() {
+            let stone: u64 = token.parse().map_err(|_| {
+                ParseError::at(idx + 1, line, token, "a non-negative 64-bit number")
+            })?;
+            stones.push(u128::from(stone));
+        }
+    }
+    Ok(stones)
+}
+
+/// Lanternfish-style solution: group identical stones and blink them together.
+pub fn count_stones_breadth_first(numbers: &[u128], blinks: usize) -> usize {
+    // Initialize our groups
+    let mut stone_groups: HashMap<u128, usize> = HashMap::new();
+    for &n in numbers {
+        *stone_groups.entry(n).or_insert(0) += 1;
+    }
+
+    for i in 0..blinks {
+        let mut new_groups: HashMap<u128, usize> = HashMap::new();
+
+        for (stone, &count) in stone_groups.iter() {
+            let digits = count_digits(*stone);
+            if *stone == 0 {
+                // All zeros become ones
+                *new_groups.entry(1).or_insert(0) += count;
+            } else if digits.is_multiple_of(2) {
+                // Even-digit stones split into two parts
+                let (front, back) = get_digit_parts(*stone, digits);
+                *new_groups.entry(front).or_insert(0) += count;
+                *new_groups.entry(back).or_insert(0) += count;
+            } else {
+                // Odd-digit stones multiply by 2024
+                *new_groups.entry(stone * 2024).or_insert(0) += count;
+            }
+        }
+
+        stone_groups = new_groups;
+        let total_stones: usize = stone_groups.values().sum();
+        trace!("Blink {} Stones: {}", i + 1, total_stones);
+    }
+
+    let final_count: usize = stone_groups.values().sum();
+    debug!("Breadth-first final count: {}", final_count);
+    final_count
+}
+
+/// Follow each stone down to the last blink, short-cutting through a precomputed table.
+pub fn count_stones_depth_first(numbers: &[u128], blinks: usize) -> usize {
+    debug!("Precomputing lookup table...");
+    count_stones_with_lookup(
+        numbers,
+        blinks,
+        &precompute_digits(LOOKUP_MAX, LOOKUP_DEPTH),
+    )
+}
+
+fn count_stones_with_lookup(numbers: &[u128], blinks: usize, lookup_table: &[Vec<usize>]) -> usize {
+    let mut total_stones = 0;
+    for (i, &number) in numbers.iter().enumerate() {
+        let stones = process_stone_depth_with_lookup(number, 0, blinks, lookup_table);
+        trace!("Number {} generated {} stones", i + 1, stones);
+        total_stones += stones;
+    }
+    debug!("Depth-first final count: {}", total_stones);
+    total_stones
+}
+
+pub struct Stones(Vec<u128>);
+
+impl Solution for Stones {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        parse_stones(input).map(Stones)
+    }
+
+    fn part1(&self) -> usize {
+        count_stones_breadth_first(&self.0, 25)
+    }
+
+    fn part2(&self) -> usize {
+        count_stones_breadth_first(&self.0, 75)
+    }
+
+    fn variants() -> Vec<Variant<Self>> {
+        vec![
+            ("breadth_first", |stones| {
+                count_stones_breadth_first(&stones.0, 25).to_string()
+            }),
+            ("depth_first", |stones| {
+                count_stones_depth_first(&stones.0, 25).to_string()
+            }),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::sync::OnceLock;
+
+    /// A smaller table than the real one, so that it is quick to build and
+    /// the cases exercise stones both inside and outside it. Every case shares it.
+    fn lookup() -> &'static [Vec<usize>] {
+        static TABLE: OnceLock<Vec<Vec<usize>>> = OnceLock::new();
+        TABLE.get_or_init(|| precompute_digits(1000, 12))
+    }
+
+    const SAMPLE: &str = "125 17\n";
+
+    #[test]
+    fn sample() {
+        let stones = Stones::parse(SAMPLE).unwrap();
+        assert_eq!(count_stones_breadth_first(&stones.0, 6), 22);
+        assert_eq!(stones.part1(), 55312);
+        assert_eq!(stones.part2(), 65601038650482);
+    }
+
+    #[test]
+    fn digit_parts() {
+        assert_eq!(get_digit_parts(17, 2), (1, 7));
+        assert_eq!(get_digit_parts(253000, 6), (253, 0));
+        assert_eq!(get_digit_parts(1000, 4), (10, 0));
+        assert_eq!(get_digit_parts(28676032, 8), (2867, 6032));
+    }
+
+    #[test]
+    fn largest_stones() {
+        // The 19-digit stone is multiplied by 2024 twice before it splits
+        let stones = Stones::parse(&format!("{} 9999999999999999999\n", u64::MAX)).unwrap();
+        assert_eq!(count_stones_breadth_first(&stones.0, 3), 4 + 2);
+        assert!(stones.part1() > 0);
+        assert_eq!(
+            Stones::parse("1 -3\n").err().unwrap().to_string(),
+            "1:3: expected a non-negative 64-bit number, found '-3'"
+        );
+    }
+
+    #[test]
+    fn both_methods_agree_on_the_sample() {
+        let stones = Stones::parse(SAMPLE).unwrap();
+        assert_eq!(count_stones_with_lookup(&stones.0, 25, lookup()), 55312);
+    }
+
+    proptest! {
+        #[test]
+        fn depth_first_matches_breadth_first(
+            stones in prop::collection::vec(0..=u128::from(u64::MAX), 1..5),
+            blinks in 0usize..40,
+        ) {
+            prop_assert_eq!(
+                count_stones_with_lookup(&stones, blinks, lookup()),
+                count_stones_breadth_first(&stones, blinks)
+            );
+        }
+    }
+}
@@ -0,0 +1,229 @@
+use libs::viz::{self, Frame};
+use libs::{debug, trace, Direction, Grid, ParseError, Point, Solution};
+
+#[derive(Debug, Clone)]
+struct Cell {
+    symbol: char,
+    color: i8, // -1 for uncolored, 1-16 for colors
+}
+
+impl Cell {
+    fn new(symbol: char) -> Self {
+        Cell {
+            symbol,
+            color: -1, // Initially uncolored
+        }
+    }
+
+    fn set_color(&mut self, color: i8) {
+        assert!((1..=16).contains(&color), "Color must be between 1 and 16");
+        self.color = color;
+    }
+}
+
+fn count_sides(grid: &Grid<Cell>, start: Point) -> i32 {
+    trace!(
+        "\nProcessing region '{}' at ({}, {})",
+        grid[start].symbol,
+        start.y,
+        start.x
+    );
+    let symbol = grid[start].symbol;
+    let color = grid[start].color;
+
+    // Check if this is a single cell region
+    let has_neighbor = Direction::ALL
+        .iter()
+        .any(|&dir| can_move(grid, start.step(dir), symbol, color));
+    if !has_neighbor {
+        return 4; // Single cell is a square
+    }
+
+    // Find first valid direction
+    let Some(mut dir) = Direction::ALL
+        .into_iter()
+        .find(|&dir| can_move(grid, start.step(dir), symbol, color))
+    else {
+        return 4; // Fallback if we can't find a valid direction
+    };
+
+    let mut sides = 0;
+
+    // Make the first move
+    let mut curr = start.step(dir);
+    let first = curr;
+    let start_dir = dir;
+
+    let mut steps = 0;
+    loop {
+        steps += 1;
+
+        trace!(
+            "  Step {}: At ({}, {}), facing {:?}, sides={}",
+            steps,
+            curr.y,
+            curr.x,
+            dir,
+            sides
+        );
+
+        // Try to turn left first
+        let left_dir = dir.turn_left();
+        let next = curr.step(left_dir);
+
+        if can_move(grid, next, symbol, color) {
+            // Can turn left
+            sides += 1;
+            dir = left_dir;
+            curr = next;
+        } else {
+            // Try going straight
+            let next = curr.step(dir);
+
+            if can_move(grid, next, symbol, color) {
+                // Can go straight
+                curr = next;
+            } else {
+                // Must turn right
+                loop {
+                    // we increment every time we turn right, even if we don't move
+                    sides += 1;
+                    dir = dir.turn_right();
+                    let next = curr.step(dir);
+                    if can_move(grid, next, symbol, color) {
+                        curr = next;
+                        break;
+                    }
+                }
+            }
+        }
+
+        if curr == first && dir == start_dir {
+            break;
+        }
+    }
+
+    sides
+}
+
+fn can_move(grid: &Grid<Cell>, p: Point, symbol: char, color: i8) -> bool {
+    grid.get(p)
+        .is_some_and(|cell| cell.symbol == symbol && cell.color == color)
+}
+
+fn get_next_color(last_color: i8) -> i8 {
+    // Use a prime number to get good distribution
+    ((last_color + 7) % 16) + 1
+}
+
+// Return (perimeter, area)
+fn depth_first_search(grid: &mut Grid<Cell>, p: Point, color: i8) -> (i32, i32) {
+    let current_symbol = grid[p].symbol;
+    let mut perimeter = 4; // Start with 4 for new cell
+    let mut area = 1; // Start with area of 1 for current cell
+    grid[p].set_color(color);
+
+    // Check all four directions: north, east, south, west
+    let neighbours: Vec<Point> = grid.neighbours4(p).collect();
+    for next in neighbours {
+        let neighbor = &grid[next];
+
+        if neighbor.symbol == current_symbol {
+            perimeter -= 1; // Decrease perimeter for matching symbol
+
+            if neighbor.color == -1 {
+                // Recursively search this direction
+                let (sub_perimeter, sub_area) = depth_first_search(grid, next, color);
+                perimeter += sub_perimeter;
+                area += sub_area;
+            }
+        }
+    }
+    (perimeter, area)
+}
+
+/// Returns (sum of perimeter * area, sum of area * sides).
+fn color_all_regions(grid: &mut Grid<Cell>) -> (i32, i32) {
+    let mut last_color = 0;
+    let mut total_score = 0;
+    let mut total_score2 = 0;
+    for p in grid.points() {
+        if grid[p].color == -1 {
+            last_color = get_next_color(last_color);
+            let (perimeter, area) = depth_first_search(grid, p, last_color);
+            let sides = count_sides(grid, p);
+            let region_score = perimeter * area;
+            let region_score2 = area * sides;
+            total_score += region_score;
+            total_score2 += region_score2;
+            trace!(
+                "Region at ({}, {}) with color {} has perimeter {}, area {}, sides {}, score {}, score2 {}",
+                p.y, p.x, last_color, perimeter, area, sides, region_score, region_score2
+            );
+        }
+    }
+    debug!("\nTotal score (sum of perimeter * area): {}", total_score);
+    debug!("Total score2 (sum of area * sides): {}", total_score2);
+    (total_score, total_score2)
+}
+
+fn print_colored(grid: &Grid<Cell>) {
+    if !viz::enabled() {
+        return;
+    }
+    let frame = Frame::new(grid, |cell| {
+        (cell.symbol, viz::palette(cell.color as usize))
+    });
+    viz::show(&frame.caption("Final colored grid"));
+}
+
+pub struct Garden(Grid<Cell>);
+
+impl Garden {
+    /// Colour a fresh copy of the garden, returning (perimeter price, sides price).
+    fn price(&self) -> (i32, i32) {
+        let mut grid = self.0.clone();
+        let scores = color_all_regions(&mut grid);
+        print_colored(&grid);
+        scores
+    }
+}
+
+impl Solution for Garden {
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let letters = Grid::parse_chars(input, "ABCDEFGHIJKLMNOPQRSTUVWXYZ")?;
+        Ok(Garden(letters.map(|&symbol| Cell::new(symbol))))
+    }
+
+    fn part1(&self) -> i32 {
+        self.price().0
+    }
+
+    fn part2(&self) -> i32 {
+        self.price().1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = include_str!("../../inputs/day12/data.txt");
+
+    #[test]
+    fn sample() {
+        let garden = Garden::parse(SAMPLE).unwrap();
+        assert_eq!(garden.part1(), 1930);
+        assert_eq!(garden.part2(), 1206);
+    }
+
+    #[test]
+    fn small_garden() {
+        let garden = Garden::parse("AAAA\nBBCD\nBBCC\nEEEC\n").unwrap();
+        assert_eq!(garden.part1(), 140);
+        assert_eq!(garden.part2(), 80);
+    }
+}
@@ -0,0 +1,243 @@
+use libs::error::{self, ParseError};
+use libs::{debug, metrics, trace, Solution};
+use nalgebra::{Matrix2, Vector2};
+
+#[derive(Debug, Clone)]
+struct Coord {
+    x: i128,
+    y: i128,
+}
+
+#[derive(Debug, Clone)]
+struct Machine {
+    button_a: Coord,
+    button_b: Coord,
+    prize: Coord,
+}
+
+/// Parse `<label>: X<sep><x>, Y<sep><y>`, where `sep` is '+' for buttons and '=' for the prize.
+/// Both numbers must fit in an `i64` and not be negative, which keeps every
+/// product in `solve_machine` of a press count and a coordinate within `i128`.
+fn parse_line(
+    line_no: usize,
+    line: &str,
+    label: &str,
+    sep: char,
+) -> Result<(i128, i128), ParseError> {
+    let Some(coords) = line
+        .strip_prefix(label)
+        .and_then(|rest| rest.strip_prefix(':'))
+    else {
+        return Err(ParseError::at(
+            line_no,
+            line,
+            line,
+            format!("'{}: ...'", label),
+        ));
+    };
+
+    let Some((x, y)) = coords.split_once(',') else {
+        return Err(ParseError::at(line_no, line, coords, "'X.., Y..'"));
+    };
+
+    let coord = |token: &str, axis: char| {
+        let token = token.trim();
+        match token.strip_prefix(axis).and_then(|t| t.strip_prefix(sep)) {
+            Some(value) => match error::number::<i64>(line_no, line, value)? {
+                number @ 0.. => Ok(i128::from(number)),
+                _ => Err(ParseError::at(
+                    line_no,
+                    line,
+                    value,
+                    "a non-negative number",
+                )),
+            },
+            None => Err(ParseError::at(
+                line_no,
+                line,
+                token,
+                format!("'{}{}<number>'", axis, sep),
+            )),
+        }
+    };
+    Ok((coord(x, 'X')?, coord(y, 'Y')?))
+}
+
+fn parse_machines(content: &str) -> Result<Vec<Machine>, ParseError> {
+    let mut machines = Vec::new();
+    let mut lines = content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| (idx + 1, line.trim_end()));
+
+    // Each machine is three lines: Button A, Button B and the Prize
+    while let Some((line_no, line)) = lines.next() {
+        let a = parse_line(line_no, line, "Button A", '+')?;
+        let (line_no, line) = lines
+            .next()
+            .ok_or_else(|| ParseError::end_of_input(line_no + 1, "'Button B: ...'"))?;
+        let b = parse_line(line_no, line, "Button B", '+')?;
+        let (line_no, line) = lines
+            .next()
+            .ok_or_else(|| ParseError::end_of_input(line_no + 1, "'Prize: ...'"))?;
+        let prize = parse_line(line_no, line, "Prize", '=')?;
+
+        machines.push(Machine {
+            button_a: Coord { x: a.0, y: a.1 },
+            button_b: Coord { x: b.0, y: b.1 },
+            prize: Coord {
+                x: prize.0,
+                y: prize.1,
+            },
+        });
+    }
+
+    Ok(machines)
+}
+
+fn solve_machine(machine: &Machine) -> Option<(i128, i128)> {
+    // Create the coefficient matrix A and vector b for Ax = b
+    let a = Matrix2::new(
+        machine.button_a.x as f64,
+        machine.button_b.x as f64,
+        machine.button_a.y as f64,
+        machine.button_b.y as f64,
+    );
+
+    let b = Vector2::new(machine.prize.x as f64, machine.prize.y as f64);
+
+    // Solve the system of equations
+    match a.try_inverse() {
+        Some(a_inv) => {
+            let solution = a_inv * b;
+            let n2 = solution[0].round();
+            let n1 = solution[1].round();
+
+            if n1 >= 0.0 && n2 >= 0.0 {
+                let n1_i = n1 as i128;
+                let n2_i = n2 as i128;
+
+                // Verify solution with exact arithmetic; presses too many to
+                // count cannot land on the prize
+                let reaches = |a: i128, b: i128, prize: i128| {
+                    n2_i.checked_mul(a)
+                        .zip(n1_i.checked_mul(b))
+                        .and_then(|(a, b)| a.checked_add(b))
+                        == Some(prize)
+                };
+                let x_valid = reaches(machine.button_a.x, machine.button_b.x, machine.prize.x);
+                let y_valid = reaches(machine.button_a.y, machine.button_b.y, machine.prize.y);
+
+                if x_valid && y_valid {
+                    return Some((n2_i, n1_i));
+                }
+            }
+        }
+        None => return None,
+    }
+    None
+}
+
+/// Part two puts every prize this much further away on both axes.
+const PRIZE_OFFSET: i128 = 10_000_000_000_000;
+
+fn total_tokens(machines: &[Machine]) -> i128 {
+    let mut total_tokens: i128 = 0;
+    let mut winnable = 0;
+
+    debug!("Found {} machines:", machines.len());
+    for (i, machine) in machines.iter().enumerate() {
+        trace!("\nMachine {}:", i + 1);
+        trace!(
+            "  Button A: ({}, {})",
+            machine.button_a.x,
+            machine.button_a.y
+        );
+        trace!(
+            "  Button B: ({}, {})",
+            machine.button_b.x,
+            machine.button_b.y
+        );
+        trace!("  Prize: ({}, {})", machine.prize.x, machine.prize.y);
+
+        // Try to solve the machine
+        match solve_machine(machine) {
+            Some((a, b)) => {
+                trace!("  Solution found: Press A {} times and B {} times", a, b);
+                total_tokens += a * 3 + b;
+                winnable += 1;
+            }
+            None => trace!("  No solution found"),
+        }
+    }
+
+    debug!("Total Tokens: {}", total_tokens);
+    metrics::record("machines", machines.len());
+    metrics::record("winnable", winnable);
+    total_tokens
+}
+
+pub struct Arcade(Vec<Machine>);
+
+impl Solution for Arcade {
+    type Answer1 = i128;
+    type Answer2 = i128;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        parse_machines(input).map(Arcade)
+    }
+
+    fn part1(&self) -> i128 {
+        total_tokens(&self.0)
+    }
+
+    fn part2(&self) -> i128 {
+        let mut machines = self.0.clone();
+        for machine in machines.iter_mut() {
+            machine.prize.x += PRIZE_OFFSET;
+            machine.prize.y += PRIZE_OFFSET;
+        }
+        total_tokens(&machines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = include_str!("../../inputs/day13/data.txt");
+
+    #[test]
+    fn sample() {
+        let arcade = Arcade::parse(SAMPLE).unwrap();
+        assert_eq!(arcade.part1(), 480);
+        assert_eq!(arcade.part2(), 875318608908);
+    }
+
+    #[test]
+    fn extreme_values() {
+        let far = 1_i64 << 62;
+        let machine = format!("Button A: X+1, Y+0\nButton B: X+0, Y+1\nPrize: X={far}, Y=0\n");
+        let arcade = Arcade::parse(&machine).unwrap();
+        assert_eq!(arcade.part1(), 3 * i128::from(far));
+        assert_eq!(arcade.part2(), 4 * PRIZE_OFFSET + 3 * i128::from(far));
+
+        // Checking the guess multiplies press counts by the largest coordinates
+        let max = i64::MAX;
+        let machine =
+            format!("Button A: X+{max}, Y+1\nButton B: X+1, Y+{max}\nPrize: X=1, Y={max}\n");
+        assert_eq!(Arcade::parse(&machine).unwrap().part1(), 1);
+
+        let too_far = "Button A: X+1, Y+0\nButton B: X+0, Y+1\n\
+                       Prize: X=170141183460469231731687303715884105727, Y=0\n";
+        assert!(Arcade::parse(too_far).is_err());
+        assert_eq!(
+            Arcade::parse("Button A: X+-1, Y+0\n")
+                .err()
+                .unwrap()
+                .to_string(),
+            "1:13: expected a non-negative number, found '-1'"
+        );
+    }
+}
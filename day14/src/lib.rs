@@ -0,0 +1,261 @@
+use libs::error::{self, ParseError};
+use libs::viz::{self, Frame};
+use libs::{debug, trace, Grid, Point, Solution};
+
+/// Size of the room the robots patrol.
+#[derive(Debug, Copy, Clone)]
+struct Area {
+    width: i32,
+    height: i32,
+}
+
+const SAMPLE_AREA: Area = Area {
+    width: 11,
+    height: 7,
+};
+const FULL_AREA: Area = Area {
+    width: 101,
+    height: 103,
+};
+
+#[derive(Debug, Copy, Clone)]
+struct Robot {
+    x: i32,
+    y: i32,
+    x_v: i32,
+    y_v: i32,
+}
+
+/// Parse `<prefix><a>,<b>`, e.g. `p=0,4`.
+fn parse_pair(
+    line_no: usize,
+    line: &str,
+    token: &str,
+    prefix: &str,
+) -> Result<(i32, i32), ParseError> {
+    let Some((a, b)) = token
+        .strip_prefix(prefix)
+        .and_then(|pair| pair.split_once(','))
+    else {
+        return Err(ParseError::at(
+            line_no,
+            line,
+            token,
+            format!("'{}<x>,<y>'", prefix),
+        ));
+    };
+    Ok((
+        error::number(line_no, line, a)?,
+        error::number(line_no, line, b)?,
+    ))
+}
+
+fn parse_line(line_no: usize, line: &str) -> Result<Robot, ParseError> {
+    let mut parts = line.split_whitespace();
+    let position = parts.next().unwrap_or(line);
+    let (x, y) = parse_pair(line_no, line, position, "p=")?;
+    if !(0..FULL_AREA.width).contains(&x) || !(0..FULL_AREA.height).contains(&y) {
+        let expected = format!("a position inside {}x{}", FULL_AREA.width, FULL_AREA.height);
+        return Err(ParseError::at(line_no, line, position, expected));
+    }
+
+    let velocity = parts.next().unwrap_or(&line[line.len()..]);
+    let (x_v, y_v) = parse_pair(line_no, line, velocity, "v=")?;
+    // Keeps `position + velocity` from overflowing before it is wrapped
+    if x_v.abs() >= FULL_AREA.width || y_v.abs() >= FULL_AREA.height {
+        let expected = format!("a velocity below {}x{}", FULL_AREA.width, FULL_AREA.height);
+        return Err(ParseError::at(line_no, line, velocity, expected));
+    }
+
+    if let Some(extra) = parts.next() {
+        return Err(ParseError::at(line_no, line, extra, "end of line"));
+    }
+
+    Ok(Robot { x, y, x_v, y_v })
+}
+
+fn parse_robots(content: &str) -> Result<Vec<Robot>, ParseError> {
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| parse_line(idx + 1, line))
+        .collect()
+}
+
+fn move_robots(robots: &mut [Robot], area: Area) {
+    for robot in robots.iter_mut() {
+        // Handle negative coordinates by adding grid size before modulo
+        robot.x = ((robot.x + robot.x_v) % area.width + area.width) % area.width;
+        robot.y = ((robot.y + robot.y_v) % area.height + area.height) % area.height;
+    }
+}
+
+fn draw_map(robots: &[Robot], area: Area, caption: String) {
+    if !viz::enabled() {
+        return;
+    }
+
+    // Count the robots on each tile
+    let mut counts = Grid::new(area.width as usize, area.height as usize, 0u32);
+    for robot in robots {
+        counts[Point::new(robot.x, robot.y)] += 1;
+    }
+
+    let frame = Frame::new(&counts, |&count| match count {
+        0 => '.',
+        // More than nine robots on a tile is drawn as nine
+        n => char::from_digit(n.min(9), 10).unwrap_or('9'),
+    });
+    viz::show(&frame.caption(caption));
+}
+
+fn check_unique(robots: &[Robot], area: Area) -> bool {
+    // Create grid to track positions
+    let mut grid = vec![vec![0; area.height as usize]; area.width as usize];
+
+    // Count robots in each position
+    for robot in robots {
+        grid[robot.x as usize][robot.y as usize] += 1;
+        // If we find any position with more than one robot, return false
+        if grid[robot.x as usize][robot.y as usize] > 1 {
+            return false;
+        }
+    }
+
+    true
+}
+
+fn calc_risk(robots: &[Robot], area: Area) -> u64 {
+    let mid_x = area.width / 2;
+    let mid_y = area.height / 2;
+    let mut quadrant_counts = [0_u64; 4]; // [top_left, top_right, bottom_left, bottom_right]
+
+    for robot in robots {
+        // Skip robots on the dividing lines
+        if robot.x == mid_x || robot.y == mid_y {
+            continue;
+        }
+
+        // Determine which quadrant the robot is in
+        let quadrant = match (robot.x < mid_x, robot.y < mid_y) {
+            (true, true) => 0,   // top-left
+            (false, true) => 1,  // top-right
+            (true, false) => 2,  // bottom-left
+            (false, false) => 3, // bottom-right
+        };
+
+        quadrant_counts[quadrant] += 1;
+    }
+
+    // Multiply all quadrant counts together
+    quadrant_counts.iter().product()
+}
+
+/// The puzzle examples use a small room, the real inputs the full-size one.
+fn area_for(robots: &[Robot]) -> Area {
+    let fits_sample = robots
+        .iter()
+        .all(|robot| robot.x < SAMPLE_AREA.width && robot.y < SAMPLE_AREA.height);
+    if fits_sample {
+        SAMPLE_AREA
+    } else {
+        FULL_AREA
+    }
+}
+
+pub struct Bathroom {
+    robots: Vec<Robot>,
+    area: Area,
+}
+
+impl Solution for Bathroom {
+    type Answer1 = u64;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let robots = parse_robots(input)?;
+        let area = area_for(&robots);
+        Ok(Bathroom { robots, area })
+    }
+
+    fn part1(&self) -> u64 {
+        safety_factor(self.robots.clone(), self.area)
+    }
+
+    /// Empty when no step ever puts every robot on its own tile.
+    fn part2(&self) -> String {
+        steps_to_tree(self.robots.clone(), self.area)
+            .map_or_else(String::new, |steps| steps.to_string())
+    }
+}
+
+fn safety_factor(mut robots: Vec<Robot>, area: Area) -> u64 {
+    for _ in 0..100 {
+        move_robots(&mut robots, area);
+    }
+
+    let risk = calc_risk(&robots, area);
+    debug!("Risk factor: {}", risk);
+    risk
+}
+
+/// The first step at which no two robots share a tile. Every robot is back
+/// where it started after `width * height` steps, so if none is found by
+/// then, there is none.
+fn steps_to_tree(mut robots: Vec<Robot>, area: Area) -> Option<u64> {
+    let period = (area.width * area.height) as u64;
+    for num_steps in 1..=period {
+        move_robots(&mut robots, area);
+        if viz::enabled() {
+            draw_map(&robots, area, format!("Step {}", num_steps));
+        }
+
+        if check_unique(&robots, area) {
+            debug!("All robots in unique positions at step {}", num_steps);
+            let risk = calc_risk(&robots, area);
+            debug!("Steps: {}", num_steps);
+            debug!("Risk: {}", risk);
+            return Some(num_steps);
+        }
+    }
+
+    trace!("Robots repeat after {} steps without a tree", period);
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = include_str!("../../inputs/day14/data.txt");
+
+    #[test]
+    fn sample() {
+        let bathroom = Bathroom::parse(SAMPLE).unwrap();
+        assert_eq!(bathroom.part1(), 12);
+    }
+
+    #[test]
+    fn first_step_with_every_robot_apart() {
+        // The puzzle has no part 2 answer for the example. Here the walker
+        // lands on the robot standing still at step 1 and is past it at 2.
+        let bathroom = Bathroom::parse("p=0,0 v=1,0\np=1,0 v=0,0\n").unwrap();
+        assert_eq!(bathroom.part2(), "2");
+    }
+
+    #[test]
+    fn crowded_quadrants() {
+        // 500 robots standing still in each corner of the full room
+        let corners = "p=0,0 v=0,0\np=100,0 v=0,0\np=0,102 v=0,0\np=100,102 v=0,0\n";
+        let bathroom = Bathroom::parse(&corners.repeat(500)).unwrap();
+        assert_eq!(bathroom.part1(), 500_u64.pow(4));
+    }
+
+    #[test]
+    fn twin_robots_never_make_a_tree() {
+        let twins = "p=0,0 v=1,1\np=0,0 v=1,1\np=50,50 v=3,-2\n";
+        let bathroom = Bathroom::parse(twins).unwrap();
+        assert_eq!(bathroom.part2(), "");
+    }
+}
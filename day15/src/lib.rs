@@ -0,0 +1,416 @@
+use libs::error::{self, ParseError};
+use libs::viz::{self, Cell, Color, Frame};
+use libs::{debug, metrics, trace, Direction, Grid, Point, Solution};
+
+#[derive(Debug, Copy, Clone)]
+enum Thing {
+    Box,
+    Wall,
+    Robot,
+    Empty,
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Item {
+    x: i32,
+    y: i32,
+    thing: Thing,
+}
+
+type Map = Grid<Item>;
+
+/// Build the double-width map of part two from the narrow one.
+fn parse_map(grid: &Grid<char>) -> (Map, Item) {
+    let empty = Item {
+        x: 0,
+        y: 0,
+        thing: Thing::Empty,
+    };
+    let mut map = Grid::new(grid.width() * 2, grid.height(), empty);
+    let mut robot = empty;
+
+    for (p, &ch) in grid.iter() {
+        let left = Point::new(p.x * 2, p.y); // Double x for expanded map
+        let right = left.step(Direction::East);
+        let item = |at: Point, thing| Item {
+            x: at.x,
+            y: at.y,
+            thing,
+        };
+
+        match ch {
+            '@' => {
+                robot = item(left, Thing::Robot);
+                map[left] = robot;
+                map[right] = item(right, Thing::Empty);
+            }
+            // ## for walls
+            '#' => {
+                map[left] = item(left, Thing::Wall);
+                map[right] = item(right, Thing::Wall);
+            }
+            'O' => {
+                // Both map positions point to the same Item, which stores the left coordinate
+                let box_item = item(left, Thing::Box);
+                map[left] = box_item;
+                map[right] = box_item;
+            }
+            // .. for empty space
+            _ => {
+                map[left] = item(left, Thing::Empty);
+                map[right] = item(right, Thing::Empty);
+            }
+        }
+    }
+
+    (map, robot)
+}
+
+fn parse_moves(content: &str, first_line: usize) -> Result<Vec<Direction>, ParseError> {
+    let mut moves = Vec::new();
+    for (idx, line) in content.lines().enumerate() {
+        for (pos, ch) in line.chars().enumerate() {
+            moves.push(match ch {
+                '^' => Direction::North,
+                'v' => Direction::South,
+                '<' => Direction::West,
+                '>' => Direction::East,
+                _ => return Err(error::bad_char(first_line + idx, pos, ch, "one of '^v<>'")),
+            });
+        }
+    }
+    Ok(moves)
+}
+
+fn update_robot_position(map: &mut Map, robot: &mut Item, new_x: i32, new_y: i32) {
+    // Clear old position
+    map[Point::new(robot.x, robot.y)].thing = Thing::Empty;
+    // Update robot position
+    robot.x = new_x;
+    robot.y = new_y;
+    // Set new position
+    map[Point::new(new_x, new_y)].thing = Thing::Robot;
+}
+
+fn find_adjacent_boxes(
+    map: &Map,
+    start_x: i32,
+    start_y: i32,
+    movement: Direction,
+) -> Vec<(i32, i32)> {
+    let mut boxes = Vec::new();
+    let mut to_check = vec![(start_x, start_y)];
+    let mut checked = Vec::new();
+
+    while let Some((x, y)) = to_check.pop() {
+        if checked.contains(&(x, y)) {
+            continue;
+        }
+        checked.push((x, y));
+
+        // If this is a box, add its left coordinate
+        if matches!(map[Point::new(x, y)].thing, Thing::Box) {
+            let box_left_x = map[Point::new(x, y)].x; // Get stored left coordinate
+            if !boxes.contains(&(box_left_x, y)) {
+                boxes.push((box_left_x, y));
+            }
+
+            let next_positions = match movement {
+                Direction::East => vec![(x + 2, y)], // right: check right
+                Direction::West => vec![(x - 2, y)], // left: check left
+                Direction::North | Direction::South => {
+                    let mut positions = Vec::new();
+                    let check_y = y + movement.delta().dy;
+                    let current_box_x = map[Point::new(x, y)].x;
+
+                    // For vertical movement, check only positions that could actually overlap
+                    // That's current_box_x-1, current_box_x, and current_box_x+1
+                    for check_x in (current_box_x - 1)..=(current_box_x + 1) {
+                        if let Some(cell) = map.get(Point::new(check_x, check_y)) {
+                            if matches!(cell.thing, Thing::Box) {
+                                let box_x = cell.x;
+                                // Only add if this box actually overlaps with our current box
+                                if (box_x == current_box_x) ||  // Same position
+                                   (box_x + 1 == current_box_x) ||  // Box to our left
+                                   (box_x == current_box_x + 1)
+                                {
+                                    // Box to our right
+                                    positions.push((box_x, check_y));
+                                }
+                            }
+                        }
+                    }
+                    positions
+                }
+            };
+
+            for (new_x, new_y) in next_positions {
+                to_check.push((new_x, new_y));
+            }
+        }
+    }
+    boxes
+}
+
+fn can_move_adjacent_boxes(map: &Map, boxes: &[(i32, i32)], movement: Direction) -> bool {
+    let delta = movement.delta();
+    for &(x, y) in boxes {
+        let target_x = x + delta.dx;
+        let target_y = y + delta.dy;
+
+        trace!(
+            "Checking box at ({}, {}) moving to ({}, {})",
+            x,
+            y,
+            target_x,
+            target_y
+        );
+
+        // Check both positions the box will occupy
+        for dx in 0..2 {
+            let check_x = target_x + dx;
+            // Check for walls or other obstacles
+            match map[Point::new(check_x, target_y)].thing {
+                Thing::Empty => continue,
+                Thing::Wall => {
+                    trace!(
+                        "Box at ({}, {}) blocked by wall at ({}, {})",
+                        x,
+                        y,
+                        check_x,
+                        target_y
+                    );
+                    return false;
+                }
+                Thing::Box => {
+                    let blocking_box_x = map[Point::new(check_x, target_y)].x;
+                    // If we hit a box, check if either its left or right position is part of our moving group
+                    if !boxes.contains(&(blocking_box_x, target_y)) {
+                        trace!(
+                            "Box at ({}, {}) blocked by unconnected box at ({}, {})",
+                            x,
+                            y,
+                            blocking_box_x,
+                            target_y
+                        );
+                        return false;
+                    }
+                }
+                _ => {
+                    trace!(
+                        "Box at ({}, {}) blocked by unknown obstacle at ({}, {})",
+                        x,
+                        y,
+                        check_x,
+                        target_y
+                    );
+                    return false;
+                }
+            }
+        }
+    }
+    true
+}
+
+fn move_box(map: &mut Map, x: i32, y: i32, movement: Direction) {
+    let mut curr_box = map[Point::new(x, y)];
+
+    // Clear old positions
+
+    map[Point::new(curr_box.x, curr_box.y)].thing = Thing::Empty;
+    map[Point::new(curr_box.x + 1, curr_box.y)] = map[Point::new(curr_box.x, curr_box.y)];
+
+    // Create new box at new position
+    curr_box.y += movement.delta().dy;
+    curr_box.x += movement.delta().dx;
+
+    // Set both halves to point to the same new box
+    map[Point::new(curr_box.x, curr_box.y)] = curr_box;
+    map[Point::new(curr_box.x + 1, curr_box.y)] = curr_box;
+}
+
+fn move_adjacent_boxes(map: &mut Map, boxes: &[(i32, i32)], movement: Direction) {
+    // Sort boxes by distance in direction of movement (furthest first)
+    let mut sorted_boxes = boxes.to_vec();
+    sorted_boxes.sort_by_key(|&(x, y)| match movement {
+        Direction::East => -x,  // Moving right: rightmost first
+        Direction::West => x,   // Moving left: leftmost first
+        Direction::South => -y, // Moving down: bottommost first
+        Direction::North => y,  // Moving up: topmost first
+    });
+
+    // Move each box (boxes array already contains only left coordinates)
+    for &(x, y) in &sorted_boxes {
+        move_box(map, x, y, movement);
+    }
+}
+
+fn move_robot(map: &mut Map, robot: &mut Item, movement: Direction) {
+    let new_x = robot.x + movement.delta().dx;
+    let new_y = robot.y + movement.delta().dy;
+
+    let direction = match movement {
+        Direction::West => '<',
+        Direction::East => '>',
+        Direction::North => '^',
+        Direction::South => 'v',
+    };
+    trace!("Move {}", direction);
+
+    // Check if we're moving into a box
+    let is_box_here = matches!(map[Point::new(new_x, new_y)].thing, Thing::Box);
+
+    if is_box_here {
+        // Find all connected boxes
+        let boxes = find_adjacent_boxes(map, new_x, new_y, movement);
+
+        // Check if we can move all the boxes
+        if can_move_adjacent_boxes(map, &boxes, movement) {
+            move_adjacent_boxes(map, &boxes, movement);
+            update_robot_position(map, robot, new_x, new_y);
+        } else {
+            trace!("Can't move: Box stack is blocked!");
+        }
+    } else if matches!(map[Point::new(new_x, new_y)].thing, Thing::Empty) {
+        update_robot_position(map, robot, new_x, new_y);
+    } else if matches!(map[Point::new(new_x, new_y)].thing, Thing::Wall) {
+        trace!("Can't move: There's a wall in the way!");
+    } else {
+        trace!("Can't move: Path is blocked!");
+    }
+}
+
+fn draw_map(map: &Map, robot: &Item, caption: String) {
+    if !viz::enabled() {
+        return;
+    }
+
+    let mut frame = Frame::new(map, |item| match item.thing {
+        Thing::Box => ('(', Color::Yellow).into(),
+        Thing::Wall => ('#', Color::Blue).into(),
+        Thing::Empty => Cell::from('.'),
+        Thing::Robot => ('@', Color::Red).into(),
+    });
+    // A box is stored in both its cells; the right half draws as the closing paren
+    for (p, item) in map.iter() {
+        if matches!(item.thing, Thing::Box) && p.x != item.x {
+            frame.set(p, (')', Color::Yellow));
+        }
+    }
+    frame.set(Point::new(robot.x, robot.y), ('@', Color::Red));
+    viz::show(&frame.caption(caption));
+}
+
+fn gps_sum(map: &Map) -> i32 {
+    map.iter()
+        // Only count boxes at their left position
+        .filter(|(p, item)| matches!(item.thing, Thing::Box) && p.x == item.x)
+        .map(|(p, _)| p.y * 100 + p.x)
+        .sum()
+}
+
+/// Part one warehouse: boxes are one cell wide, so a push just shifts a straight line of them.
+fn push_narrow(grid: &mut Grid<char>, robot: &mut Point, movement: Direction) {
+    let first = robot.step(movement);
+    let mut end = first;
+    while grid[end] == 'O' {
+        end = end.step(movement);
+    }
+
+    if grid[end] == '#' {
+        return;
+    }
+
+    // The last box (if any) lands in the free cell, the robot takes the first one
+    if end != first {
+        grid[end] = 'O';
+    }
+    grid[first] = '@';
+    grid[*robot] = '.';
+    *robot = first;
+}
+
+pub struct Warehouse {
+    /// The map as drawn, for the narrow warehouse of part one.
+    grid: Grid<char>,
+    /// The double-width map of part two, with the robot's start.
+    wide: (Map, Item),
+    moves: Vec<Direction>,
+}
+
+impl Solution for Warehouse {
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let Some((map, moves)) = input.split_once("\n\n") else {
+            let line = input.lines().count() + 1;
+            return Err(ParseError::end_of_input(line, "a blank line and the moves"));
+        };
+        let grid = Grid::parse_chars(map, "#.O@")?;
+        // The pushing code relies on the walls to keep everything on the map
+        grid.check_walled('#')?;
+        grid.find_one('@')?;
+
+        // The moves start after the map and the blank line
+        let moves = parse_moves(moves, map.lines().count() + 2)?;
+        Ok(Warehouse {
+            wide: parse_map(&grid),
+            grid,
+            moves,
+        })
+    }
+
+    fn part1(&self) -> i32 {
+        let mut grid = self.grid.clone();
+
+        let mut robot = grid.find(&'@').unwrap_or(Point::new(0, 0));
+        for &movement in &self.moves {
+            push_narrow(&mut grid, &mut robot, movement);
+        }
+
+        grid.find_all(&'O').map(|p| p.y * 100 + p.x).sum()
+    }
+
+    fn part2(&self) -> i32 {
+        let (mut map, mut robot) = self.wide.clone();
+
+        draw_map(&map, &robot, "Initial state".to_string());
+
+        for (step, &movement) in self.moves.iter().enumerate() {
+            move_robot(&mut map, &mut robot, movement);
+            let caption = format!("Move {}/{}: {:?}", step + 1, self.moves.len(), movement);
+            draw_map(&map, &robot, caption);
+        }
+
+        let sum = gps_sum(&map);
+        metrics::record("moves", self.moves.len());
+        debug!("GPS Sum: {}", sum);
+        sum
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn samples() {
+        let cases = [
+            (include_str!("../../inputs/day15/data.txt"), 10092, 9021),
+            (include_str!("../../inputs/day15/data0.txt"), 2028, 1751),
+            (include_str!("../../inputs/day15/data1.txt"), 908, 618),
+            (include_str!("../../inputs/day15/data3.txt"), 9021, 9634),
+        ];
+        for (input, part1, part2) in cases {
+            let warehouse = Warehouse::parse(input).unwrap();
+            assert_eq!(warehouse.part1(), part1);
+            assert_eq!(warehouse.part2(), part2);
+        }
+    }
+
+    #[test]
+    fn empty_input_is_rejected() {
+        assert!(Warehouse::parse(include_str!("../../inputs/day15/data4.txt")).is_err());
+    }
+}
@@ -0,0 +1,222 @@
+use libs::search::{self, Search};
+use libs::viz::{self, Cell, Color, Frame};
+use libs::{debug, info, metrics, trace, Direction, Grid, ParseError, Point, Solution};
+use std::collections::HashSet;
+
+/// The maze with the given tiles filled in; the start and end keep their letters.
+fn draw_map<'a>(map: &Map, layers: impl IntoIterator<Item = (&'a [Point], Color)>) -> Frame {
+    let mut frame = Frame::new(map, |thing| match thing {
+        Thing::Wall => Cell::from(('#', Color::Blue)),
+        Thing::Robot => Cell::from('S'),
+        Thing::End => Cell::from('E'),
+        Thing::Empty => Cell::from('.'),
+    });
+    for (tiles, color) in layers {
+        let open = tiles.iter().filter(|&&p| matches!(map[p], Thing::Empty));
+        frame = frame.mark(open, '█', color);
+    }
+    frame
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Thing {
+    Wall,
+    Robot,
+    End,
+    Empty,
+}
+
+type Map = Grid<Thing>;
+
+/// A position together with the direction we are facing there.
+type StateKey = (Point, Direction);
+
+fn get_valid_turns(current_dir: Direction, is_first_move: bool) -> Vec<Direction> {
+    match (current_dir, is_first_move) {
+        (dir, true) => {
+            // At first position: straight, left, right, or turn left twice
+            let mut turns = vec![
+                dir,              // straight
+                dir.turn_left(),  // left
+                dir.turn_right(), // right
+                dir.reverse(),    // turn around
+            ];
+            turns.dedup(); // Remove any duplicates
+            turns
+        }
+        (dir, false) => {
+            // After first position: straight, left, or right only
+            vec![
+                dir,              // straight
+                dir.turn_left(),  // left
+                dir.turn_right(), // right
+            ]
+        }
+    }
+}
+
+fn get_neighbors(
+    pos: Point,
+    current_dir: Direction,
+    is_first_move: bool,
+    map: &Map,
+) -> Vec<(Point, Direction)> {
+    let mut neighbors = Vec::new();
+
+    let valid_turns = get_valid_turns(current_dir, is_first_move);
+
+    for new_dir in valid_turns {
+        let next = pos.step(new_dir);
+
+        if map
+            .get(next)
+            .is_some_and(|thing| !matches!(thing, Thing::Wall))
+        {
+            neighbors.push((next, new_dir));
+        }
+    }
+    neighbors
+}
+
+/// Search the maze from the start, facing east. A step forward costs 1 and
+/// turning on the way costs 1000 more.
+fn search_maze(map: &Map, start: Point, end: Point) -> Search<StateKey, i32> {
+    let moves = |&(position, direction): &StateKey| {
+        get_neighbors(position, direction, position == start, map)
+            .into_iter()
+            .map(move |(next, next_dir)| {
+                let cost = if next_dir == direction { 1 } else { 1001 };
+                ((next, next_dir), cost)
+            })
+    };
+    search::dijkstra((start, Direction::East), moves, |&(position, _)| {
+        position == end
+    })
+}
+
+/// Draw the shortest paths one at a time, each on top of the ones before.
+fn animate_paths(map: &Map, maze: &Search<StateKey, i32>) {
+    let cost = maze.goal_cost().unwrap_or(0);
+    let mut confirmed: Vec<Point> = Vec::new();
+    let mut found = 0;
+    for goal in maze.goals() {
+        for path in maze.all_paths(goal) {
+            let path: Vec<Point> = path.into_iter().map(|(position, _)| position).collect();
+            found += 1;
+            trace!("Path {}: {:?}", found, path);
+
+            let frame = draw_map(
+                map,
+                [(&confirmed[..], Color::White), (&path[..], Color::Yellow)],
+            )
+            .caption(format!("Shortest paths of cost {}: {} found", cost, found));
+            viz::show(&frame);
+            confirmed.extend(path);
+        }
+    }
+}
+
+pub struct Maze {
+    map: Map,
+    start: Point,
+    end: Point,
+}
+
+impl Solution for Maze {
+    type Answer1 = i32;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse_chars(input, "#.SE")?;
+        grid.check_walled('#')?;
+        let start = grid.find_one('S')?;
+        let end = grid.find_one('E')?;
+
+        let map = grid.map(|&ch| match ch {
+            '#' => Thing::Wall,
+            'S' => Thing::Robot,
+            'E' => Thing::End,
+            _ => Thing::Empty,
+        });
+        Ok(Maze { map, start, end })
+    }
+
+    fn part1(&self) -> i32 {
+        let (map, robot, end) = (&self.map, self.start, self.end);
+
+        debug!(
+            "Starting search from {:?} to {:?}",
+            (robot.x, robot.y),
+            (end.x, end.y)
+        );
+        debug!("Map dimensions: {}x{}", map.height(), map.width());
+        match search_maze(map, robot, end).goal_cost() {
+            Some(cost) => {
+                debug!("Shortest path cost: {}", cost);
+                cost
+            }
+            None => {
+                info!("No path found!");
+                0
+            }
+        }
+    }
+
+    fn part2(&self) -> usize {
+        let (map, robot, end) = (&self.map, self.start, self.end);
+        let maze = search_maze(map, robot, end);
+        let Some(cost) = maze.goal_cost() else {
+            info!("No path found!");
+            return 0;
+        };
+
+        // Every tile that some equally cheap way to the end passes through
+        let unique_tiles: HashSet<Point> = maze
+            .on_shortest_paths(maze.goals())
+            .into_iter()
+            .map(|(position, _)| position)
+            .collect();
+        let path_count = maze.count_paths(maze.goals());
+
+        debug!("\nFINAL RESULTS:");
+        debug!("Shortest path cost: {}", cost);
+        debug!("Number of different shortest paths: {}", path_count);
+
+        if viz::enabled() {
+            animate_paths(map, &maze);
+        }
+
+        debug!("\nPath Analysis:");
+        debug!("Total optimal paths found: {}", path_count);
+        metrics::record("cost", cost);
+        metrics::record("optimal_paths", path_count);
+        debug!(
+            "Number of unique tiles used across all paths: {}",
+            unique_tiles.len()
+        );
+        trace!("Unique tiles: {:?}", unique_tiles);
+
+        if viz::enabled() {
+            let tiles: Vec<Point> = unique_tiles.iter().copied().collect();
+            let frame = draw_map(map, [(&tiles[..], Color::White)])
+                .caption("Every tile on a shortest path");
+            viz::show(&frame);
+        }
+
+        unique_tiles.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = include_str!("../../inputs/day16/data.txt");
+
+    #[test]
+    fn sample() {
+        let maze = Maze::parse(SAMPLE).unwrap();
+        assert_eq!(maze.part1(), 7036);
+        assert_eq!(maze.part2(), 45);
+    }
+}
@@ -0,0 +1,357 @@
+use libs::error::{self, ParseError};
+use libs::{debug, metrics, trace, Solution};
+#[derive(Debug)]
+enum Status {
+    Running,
+    Halt,
+    BadHalt,
+}
+
+#[derive(Debug)]
+struct MachineState {
+    registers: Vec<i64>,
+    program: Vec<i32>,
+    status: Status,
+    output_buffer: Vec<i32>,
+}
+
+fn parse_data_file(contents: &str) -> Result<MachineState, ParseError> {
+    let mut lines = contents
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| (idx + 1, line.trim_end()))
+        .filter(|(_, line)| !line.is_empty());
+    let mut last_line = 0;
+    let mut next_line = |expected: &str| {
+        let (line_no, line) = lines
+            .next()
+            .ok_or_else(|| ParseError::end_of_input(last_line + 1, expected))?;
+        last_line = line_no;
+        match line.strip_prefix(expected) {
+            Some(value) => Ok((line_no, line, value.trim())),
+            None => Err(ParseError::at(
+                line_no,
+                line,
+                line,
+                format!("'{}'", expected),
+            )),
+        }
+    };
+
+    let mut registers = Vec::with_capacity(3);
+    for name in ["Register A:", "Register B:", "Register C:"] {
+        let (line_no, line, value) = next_line(name)?;
+        match error::number(line_no, line, value)? {
+            register @ 0.. => registers.push(register),
+            _ => {
+                return Err(ParseError::at(
+                    line_no,
+                    line,
+                    value,
+                    "a non-negative number",
+                ))
+            }
+        }
+    }
+
+    let (line_no, line, values) = next_line("Program:")?;
+    let mut program = Vec::new();
+    for token in values.split(',') {
+        let token = token.trim();
+        match token.parse::<i32>() {
+            Ok(value @ 0..=7) => program.push(value),
+            _ => return Err(ParseError::at(line_no, line, token, "a 3-bit number")),
+        }
+    }
+
+    if let Some((line_no, line)) = lines.next() {
+        return Err(ParseError::at(line_no, line, line, "end of input"));
+    }
+
+    Ok(MachineState {
+        registers,
+        program,
+        status: Status::Running,
+        output_buffer: Vec::new(),
+    })
+}
+
+impl MachineState {
+    fn get_operand_value(&mut self, operand: i32) -> Option<i64> {
+        match operand {
+            0..=3 => Some(operand.into()), // Convert i32 to i64
+            4 => self.registers.first().copied(),
+            5 => self.registers.get(1).copied(),
+            6 => self.registers.get(2).copied(),
+            7 => {
+                self.status = Status::BadHalt;
+                None
+            }
+            _ => {
+                self.status = Status::BadHalt;
+                None
+            }
+        }
+    }
+}
+
+/// `value / 2^power` for the division instructions. Registers are never
+/// negative, so this is a shift, and shifting every bit out leaves 0.
+fn divide(value: i64, power: i64) -> i64 {
+    u32::try_from(power)
+        .ok()
+        .and_then(|power| value.checked_shr(power))
+        .unwrap_or(0)
+}
+
+/// More instructions than any halting puzzle program needs; a program that
+/// is still running after this many is stopped.
+const MAX_STEPS: usize = 10_000_000;
+
+fn run(prog: &[i32], rega: i64, regb: i64, regc: i64) -> Vec<i32> {
+    let mut state = MachineState {
+        registers: vec![rega, regb, regc],
+        program: prog.to_vec(),
+        status: Status::Running,
+        output_buffer: Vec::new(),
+    };
+
+    let mut ip: usize = 0;
+    let mut steps = 0;
+
+    while ip < state.program.len() - 1 {
+        steps += 1;
+        if steps > MAX_STEPS {
+            debug!("Stopped after {} instructions without halting", MAX_STEPS);
+            state.status = Status::BadHalt;
+            break;
+        }
+
+        let opcode = state.program[ip];
+        let operand = state.program[ip + 1];
+
+        trace!("\nIP: {}", ip);
+        trace!("Before: Registers: {:?}", state.registers);
+        trace!("Executing opcode: {}, operand: {}", opcode, operand);
+
+        match opcode {
+            0 => {
+                if let Some(op_val) = state.get_operand_value(operand) {
+                    state.registers[0] = divide(state.registers[0], op_val);
+                }
+            }
+            1 => {
+                // bxl - directly use operand value without get_operand_value
+                state.registers[1] ^= operand as i64; // Convert operand to i64 for bitwise operation
+            }
+            2 => {
+                if let Some(op_val) = state.get_operand_value(operand) {
+                    state.registers[1] = op_val % 8;
+                }
+            }
+            3 => {
+                if let Some(op_val) = state.get_operand_value(operand) {
+                    if state.registers[0] != 0 {
+                        ip = op_val as usize;
+                        continue;
+                    }
+                }
+            }
+            4 => {
+                state.registers[1] ^= state.registers[2];
+            }
+            5 => {
+                if let Some(op_val) = state.get_operand_value(operand) {
+                    state.output_buffer.push((op_val % 8) as i32);
+                }
+            }
+            6 => {
+                if let Some(op_val) = state.get_operand_value(operand) {
+                    state.registers[1] = divide(state.registers[0], op_val);
+                }
+            }
+            7 => {
+                if let Some(op_val) = state.get_operand_value(operand) {
+                    state.registers[2] = divide(state.registers[0], op_val);
+                }
+            }
+            _ => {
+                state.status = Status::BadHalt;
+                break;
+            }
+        }
+        ip += 2;
+        trace!("After:  Registers: {:?}", state.registers);
+    }
+
+    if matches!(state.status, Status::Running) {
+        state.status = Status::Halt;
+    }
+
+    state.output_buffer
+}
+
+fn rev_eng(prog: &[i32], _reg_b: i64, _reg_c: i64) -> i64 {
+    let mut reg_a = 0;
+    let mut to_match: usize = 1;
+    let mut istart = 0;
+
+    while to_match <= prog.len() && to_match >= 1 {
+        trace!("\n--- Loop Start ---");
+        trace!(
+            "to_match: {}, reg_a: {}, istart: {}",
+            to_match,
+            reg_a,
+            istart
+        );
+        reg_a <<= 3;
+        trace!("After shift left: reg_a = {}", reg_a);
+
+        let mut found_match = false;
+        for i in istart..8 {
+            trace!("\nTrying i={}", i);
+            let output = run(prog, reg_a + i, 0, 0); // Always start with clean registers
+            let prog_slice = &prog[prog.len() - to_match..];
+            trace!(
+                "Comparing prog[-{}:] = {:?} with output = {:?}",
+                to_match,
+                prog_slice,
+                output
+            );
+
+            // Direct comparison with the output, just like Python
+            if prog_slice == output {
+                trace!("Match found with i={}", i);
+                reg_a += i;
+                to_match += 1;
+                found_match = true;
+                break;
+            }
+        }
+        istart = 0;
+
+        if !found_match {
+            trace!("No match found - backtracking");
+            to_match -= 1;
+            reg_a >>= 3;
+            istart = (reg_a % 8) + 1;
+            reg_a >>= 3;
+            trace!(
+                "Backtrack: to_match={}, new reg_a={}, new istart={}",
+                to_match,
+                reg_a,
+                istart
+            );
+            continue;
+        }
+
+        trace!("Success: adding i={} to reg_a", reg_a % 8);
+    }
+
+    debug!("\nFinal reg_a: {}", reg_a);
+    reg_a
+}
+
+fn format_output(output: &[i32]) -> String {
+    output
+        .iter()
+        .map(|val| val.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+pub struct Computer(MachineState);
+
+impl Solution for Computer {
+    type Answer1 = String;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        parse_data_file(input).map(Computer)
+    }
+
+    fn part1(&self) -> String {
+        let state = &self.0;
+
+        let output = run(
+            &state.program,
+            state.registers[0],
+            state.registers[1],
+            state.registers[2],
+        );
+
+        let output = format_output(&output);
+        debug!("\nProgram output: {}", output);
+        output
+    }
+
+    fn part2(&self) -> i64 {
+        let state = &self.0;
+
+        // Try to find the input that makes the program output itself
+        let result = rev_eng(&state.program, state.registers[1], state.registers[2]);
+        debug!("\nFound input value: {}", result);
+        let output = run(
+            &state.program,
+            result,
+            state.registers[1],
+            state.registers[2],
+        );
+        debug!("Verification output: {}", format_output(&output));
+        metrics::record("verification_output", format_output(&output));
+        debug!("\nReverse engineered register A value: {}", result);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = include_str!("../../inputs/day17/data.txt");
+
+    #[test]
+    fn sample() {
+        let computer = Computer::parse(SAMPLE).unwrap();
+        assert_eq!(computer.part1(), "4,6,3,5,6,3,5,2,1,0");
+    }
+
+    #[test]
+    fn quine() {
+        let input = "Register A: 2024\nRegister B: 0\nRegister C: 0\n\nProgram: 0,3,5,4,3,0\n";
+        assert_eq!(Computer::parse(input).unwrap().part2(), 117440);
+    }
+
+    #[test]
+    fn instructions() {
+        assert_eq!(run(&[5, 0, 5, 1, 5, 4], 10, 0, 0), vec![0, 1, 2]);
+        assert_eq!(
+            run(&[0, 1, 5, 4, 3, 0], 2024, 0, 0),
+            vec![4, 2, 5, 6, 7, 7, 7, 7, 3, 1, 0]
+        );
+        // bst, bxl and bxc, each followed by `out B`
+        assert_eq!(run(&[2, 6, 5, 5], 0, 0, 9), vec![1]);
+        assert_eq!(run(&[1, 7, 5, 5], 0, 29, 0), vec![26 % 8]);
+        assert_eq!(run(&[4, 0, 5, 5], 0, 2024, 43690), vec![44354 % 8]);
+        // bdv and cdv divide A into the other registers
+        assert_eq!(run(&[6, 2, 5, 5, 7, 3, 5, 6], 64, 0, 0), vec![0, 0]);
+        assert_eq!(run(&[6, 1, 5, 5, 7, 3, 5, 6], 44, 0, 0), vec![6, 5]);
+    }
+
+    #[test]
+    fn untrusted_registers() {
+        // Dividing by 2^100 leaves nothing rather than overflowing the shift
+        let input = "Register A: 7\nRegister B: 100\nRegister C: 0\n\nProgram: 0,5,5,4\n";
+        assert_eq!(Computer::parse(input).unwrap().part1(), "0");
+        assert_eq!(run(&[6, 5, 7, 5, 5, 5, 5, 6], 1 << 40, 64, 0), vec![0, 0]);
+
+        let negative = "Register A: -3\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1\n";
+        assert_eq!(
+            Computer::parse(negative).err().unwrap().to_string(),
+            "1:13: expected a non-negative number, found '-3'"
+        );
+
+        // Jumping back to the start with A never changing would run forever
+        assert_eq!(run(&[5, 4, 3, 0], 1, 0, 0).len(), MAX_STEPS / 2);
+    }
+}
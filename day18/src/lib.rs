@@ -0,0 +1,208 @@
+use libs::error::{self, ParseError};
+use libs::viz::{self, Cell, Color, Frame};
+use libs::{debug, info, search, Grid, Point, Solution};
+
+/// Memory space size and how many bytes fall before part one, for the example and real inputs.
+type Space = (usize, usize);
+
+const SAMPLE_SPACE: Space = (7, 12);
+const FULL_SPACE: Space = (71, 1024);
+
+/// Largest side a `space:` line may set, which keeps a stray header from
+/// allocating gigabytes.
+const MAX_DIM: usize = 4096;
+
+/// The shortest way from the top-left corner to the bottom-right one.
+fn find_path(grid: &Grid<char>) -> Option<Vec<Point>> {
+    let start = Point::new(0, 0);
+    let goal = Point::new(grid.width() as i32 - 1, grid.height() as i32 - 1);
+    let open = |&p: &Point| grid.neighbours4(p).filter(|&next| grid[next] != '#');
+    search::bfs(start, open, |&p| p == goal).path(&goal)
+}
+
+fn print_grid(grid: &Grid<char>, path: &[Point], caption: String) {
+    if !viz::enabled() {
+        return;
+    }
+
+    let open = path.iter().filter(|&&p| grid[p] == '.');
+    let frame = Frame::new(grid, |&ch| match ch {
+        '#' => Cell::from(('#', Color::DarkRed)),
+        _ => Cell::from(ch),
+    })
+    .mark(open, 'o', Color::Green);
+    viz::show(&frame.caption(caption));
+}
+
+/// The optional first line of an input that sets the space itself: its side
+/// and how many bytes fall before part one.
+const SPACE_HEADER: &str = "space:";
+
+fn parse_header(line_no: usize, line: &str) -> Result<Space, ParseError> {
+    let values = line.trim()[SPACE_HEADER.len()..].trim();
+    let expected = format!("'{} <side> <bytes>'", SPACE_HEADER);
+    let mut fields = values.split_whitespace();
+    let (Some(dim), Some(fallen), None) = (fields.next(), fields.next(), fields.next()) else {
+        return Err(ParseError::at(line_no, line, line.trim(), expected));
+    };
+    let dim: usize = error::number(line_no, line, dim)?;
+    let fallen: usize = error::number(line_no, line, fallen)?;
+    if !(1..=MAX_DIM).contains(&dim) {
+        let expected = format!("a side from 1 to {}", MAX_DIM);
+        return Err(ParseError::at(line_no, line, line.trim(), expected));
+    }
+    Ok((dim, fallen))
+}
+
+/// The bytes, and the space if the input sets it.
+fn parse_bytes(input: &str) -> Result<(Vec<Point>, Option<Space>), ParseError> {
+    let mut bytes = Vec::new();
+    let mut space = None;
+    for (idx, line) in input.lines().enumerate() {
+        let line_no = idx + 1;
+        if line.trim().is_empty() {
+            continue;
+        }
+        if line.trim_start().starts_with(SPACE_HEADER) {
+            if space.is_some() || !bytes.is_empty() {
+                return Err(ParseError::at(line_no, line, line.trim(), "'<x>,<y>'"));
+            }
+            space = Some(parse_header(line_no, line)?);
+            continue;
+        }
+        let Some((x, y)) = line.split_once(',') else {
+            return Err(ParseError::at(line_no, line, line, "'<x>,<y>'"));
+        };
+        let (x, y) = (x.trim(), y.trim());
+        let x: i32 = error::number(line_no, line, x)?;
+        let y: i32 = error::number(line_no, line, y)?;
+        let (dim, _) = space.unwrap_or(FULL_SPACE);
+        let inside = 0..dim as i32;
+        if !inside.contains(&x) || !inside.contains(&y) {
+            let expected = format!("a position inside {}x{}", dim, dim);
+            return Err(ParseError::at(line_no, line, line.trim(), expected));
+        }
+        bytes.push(Point::new(x, y));
+    }
+    Ok((bytes, space))
+}
+
+/// The examples fit in a 7x7 space, real inputs use the full 71x71 one.
+fn space_for(bytes: &[Point]) -> Space {
+    let sample_dim = SAMPLE_SPACE.0 as i32;
+    if bytes.iter().all(|p| p.x < sample_dim && p.y < sample_dim) {
+        SAMPLE_SPACE
+    } else {
+        FULL_SPACE
+    }
+}
+
+pub struct MemorySpace {
+    bytes: Vec<Point>,
+    space: Space,
+}
+
+impl Solution for MemorySpace {
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (bytes, space) = parse_bytes(input)?;
+        let space = space.unwrap_or_else(|| space_for(&bytes));
+        Ok(MemorySpace { bytes, space })
+    }
+
+    fn part1(&self) -> usize {
+        let (dim, fallen) = self.space;
+        let mut grid = Grid::new(dim, dim, '.');
+
+        for &p in self.bytes.iter().take(fallen) {
+            if let Some(cell) = grid.get_mut(p) {
+                *cell = '#';
+            }
+        }
+
+        match find_path(&grid) {
+            Some(path) => {
+                print_grid(&grid, &path, format!("After {} bytes", fallen));
+                path.len() - 1
+            }
+            None => {
+                info!("No initial path available!");
+                0
+            }
+        }
+    }
+
+    fn part2(&self) -> String {
+        let (dim, _) = self.space;
+        let mut grid = Grid::new(dim, dim, '.');
+
+        let mut current_path: Option<Vec<Point>> = None;
+
+        for (fallen, &p) in self.bytes.iter().enumerate() {
+            // Add the new coordinate to the grid
+            if let Some(cell) = grid.get_mut(p) {
+                *cell = '#';
+            }
+
+            // If we have a current path, check if this coordinate blocks it
+            let blocked = match current_path {
+                Some(ref path) => path.contains(&p),
+                None => true,
+            };
+            if blocked {
+                // Path is blocked (or this is the first byte), try to find a new path
+                current_path = find_path(&grid);
+                let Some(path) = &current_path else {
+                    debug!("No more paths available!");
+                    debug!("Last coordinate read: ({}, {})", p.x, p.y);
+                    return format!("{},{}", p.x, p.y);
+                };
+                let caption = format!("Rerouted after byte {} at {},{}", fallen + 1, p.x, p.y);
+                print_grid(&grid, path, caption);
+            }
+        }
+
+        // Print final state
+        if let Some(path) = current_path {
+            print_grid(&grid, &path, "Final grid with last valid path".to_string());
+            debug!("Path length: {}", path.len() - 1);
+        }
+
+        String::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = include_str!("../../inputs/day18/data.txt");
+
+    #[test]
+    fn sample() {
+        let space = MemorySpace::parse(SAMPLE).unwrap();
+        assert_eq!(space.part1(), 22);
+        assert_eq!(space.part2(), "6,1");
+    }
+
+    #[test]
+    fn space_header() {
+        // A wall down the middle of a 3x3 space, closed by the third byte
+        let space = MemorySpace::parse("space: 3 2\n1,0\n1,1\n1,2\n").unwrap();
+        assert_eq!(space.space, (3, 2));
+        assert_eq!(space.part1(), 4);
+        assert_eq!(space.part2(), "1,2");
+
+        // Without a header only the two puzzle spaces exist
+        let plain = MemorySpace::parse("0,0\n12,3\n").unwrap();
+        assert_eq!(plain.space, FULL_SPACE);
+        assert!(MemorySpace::parse("71,0\n").is_err());
+
+        assert!(MemorySpace::parse("space: 3 1\n3,0\n").is_err());
+        assert!(MemorySpace::parse("space: 0 1\n").is_err());
+        assert!(MemorySpace::parse("space: 5\n").is_err());
+        assert!(MemorySpace::parse("1,1\nspace: 5 1\n").is_err());
+    }
+}
@@ -0,0 +1,182 @@
+use libs::error::{self, ParseError};
+use libs::{debug, metrics, trace, Solution};
+use std::collections::HashMap;
+
+const STRIPES: &str = "wubrg";
+
+fn can_assemble_string(tokens: &[String], target: &str) -> u64 {
+    if target.is_empty() {
+        return 1;
+    }
+
+    let min_token_len = tokens.iter().map(|t| t.len()).min().unwrap();
+
+    if target.len() < min_token_len {
+        return 0;
+    }
+
+    let first_char = target.chars().next().unwrap();
+    if !tokens.iter().any(|t| t.starts_with(first_char)) {
+        return 0;
+    }
+
+    let mut memo: HashMap<usize, u64> = HashMap::new();
+
+    fn build_string(
+        tokens: &[String],
+        target: &str,
+        current_pos: usize,
+
+        min_len: usize,
+
+        memo: &mut HashMap<usize, u64>,
+    ) -> u64 {
+        if let Some(&cached_ways) = memo.get(&current_pos) {
+            return cached_ways;
+        }
+
+        if current_pos == target.len() {
+            return 1;
+        }
+
+        if current_pos > target.len() {
+            return 0;
+        }
+
+        let remaining_len = target.len() - current_pos;
+        if remaining_len < min_len {
+            return 0;
+        }
+
+        let next_char = target.chars().nth(current_pos).unwrap();
+        let mut total_ways = 0;
+
+        for token in tokens.iter().filter(|t| {
+            t.starts_with(next_char)
+                && t.len() <= remaining_len
+                && (t.len() == remaining_len || remaining_len - t.len() >= min_len)
+        }) {
+            if target[current_pos..].starts_with(token) {
+                total_ways +=
+                    build_string(tokens, target, current_pos + token.len(), min_len, memo);
+            }
+        }
+
+        memo.insert(current_pos, total_ways);
+        total_ways
+    }
+
+    build_string(tokens, target, 0, min_token_len, &mut memo)
+}
+
+pub struct Towels {
+    tokens: Vec<String>,
+    designs: Vec<String>,
+}
+
+impl Towels {
+    /// Count the arrangements for every design listed after the towel patterns.
+    fn count_ways(&self) -> Vec<u64> {
+        self.designs
+            .iter()
+            .map(|s| {
+                let ways = can_assemble_string(&self.tokens, s);
+                trace!("Processed string: {} ({} ways)", s, ways);
+                ways
+            })
+            .collect()
+    }
+}
+
+/// Patterns and designs are non-empty runs of stripe colours.
+fn check_stripes<'a>(line_no: usize, line: &str, token: &'a str) -> Result<&'a str, ParseError> {
+    if token.is_empty() {
+        return Err(ParseError::at(line_no, line, token, "a towel pattern"));
+    }
+    match token.char_indices().find(|&(_, ch)| !STRIPES.contains(ch)) {
+        Some((pos, ch)) => Err(error::bad_char(
+            line_no,
+            error::column(line, token) - 1 + pos,
+            ch,
+            "one of 'wubrg'",
+        )),
+        None => Ok(token),
+    }
+}
+
+impl Solution for Towels {
+    type Answer1 = usize;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut lines = input.lines().map(str::trim_end).enumerate();
+
+        let Some((_, patterns)) = lines.next() else {
+            return Err(ParseError::end_of_input(1, "a list of towel patterns"));
+        };
+        let tokens = patterns
+            .split(',')
+            .map(|token| check_stripes(1, patterns, token.trim()).map(String::from))
+            .collect::<Result<Vec<String>, _>>()?;
+
+        match lines.next() {
+            Some((_, "")) => {}
+            Some((idx, line)) => return Err(ParseError::at(idx + 1, line, line, "a blank line")),
+            None => return Err(ParseError::end_of_input(2, "a blank line and the designs")),
+        }
+
+        let designs = lines
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(idx, line)| check_stripes(idx + 1, line, line).map(String::from))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Towels { tokens, designs })
+    }
+
+    fn part1(&self) -> usize {
+        metrics::record("designs", self.designs.len());
+        self.count_ways().iter().filter(|&&ways| ways > 0).count()
+    }
+
+    fn part2(&self) -> u64 {
+        let total_ways = self.count_ways().iter().sum();
+        debug!("Total number of ways across all strings: {}", total_ways);
+        total_ways
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = include_str!("../../inputs/day19/data.txt");
+
+    #[test]
+    fn sample() {
+        let towels = Towels::parse(SAMPLE).unwrap();
+        assert_eq!(towels.part1(), 6);
+        assert_eq!(towels.part2(), 16);
+    }
+
+    #[test]
+    fn arrangements() {
+        let tokens: Vec<String> = ["r", "wr", "b", "g", "bwu", "rb", "gb", "br"]
+            .iter()
+            .map(|t| t.to_string())
+            .collect();
+        let expected = [
+            ("brwrr", 2),
+            ("bggr", 1),
+            ("gbbr", 4),
+            ("rrbgbr", 6),
+            ("bwurrg", 1),
+            ("brgr", 2),
+            ("ubwu", 0),
+            ("bbrgwb", 0),
+        ];
+        for (design, ways) in expected {
+            assert_eq!(can_assemble_string(&tokens, design), ways, "{}", design);
+        }
+        assert_eq!(can_assemble_string(&tokens, ""), 1);
+    }
+}
@@ -0,0 +1,496 @@
+use libs::error::{self, ParseError};
+use libs::log::{self, Level};
+use libs::{debug, metrics, trace, Solution};
+use serde::Serialize;
+use std::fmt::Write;
+
+fn parse_line(
+    line_start: usize,
+    chars: &[u8],
+    line_no: usize,
+) -> Result<(Vec<i32>, usize), ParseError> {
+    let mut numbers = Vec::new();
+    let mut current_start = line_start;
+    let mut index = line_start;
+
+    while index < chars.len() && chars[index] != b'\n' {
+        if chars[index] == b' ' {
+            let column = current_start - line_start + 1;
+            numbers.push(error::parse_digits(
+                &chars[current_start..index],
+                line_no,
+                column,
+            )?);
+            current_start = index + 1;
+        }
+        index += 1;
+    }
+
+    // Don't forget the last number in the line
+    if current_start < index {
+        let column = current_start - line_start + 1;
+        numbers.push(error::parse_digits(
+            &chars[current_start..index],
+            line_no,
+            column,
+        )?);
+    }
+
+    Ok((numbers, index))
+}
+
+/// What makes a report safe: every step between the levels that are kept
+/// goes the same way, by `min_step` to `max_step`, after removing at most
+/// `removals` levels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rules {
+    pub min_step: i32,
+    pub max_step: i32,
+    pub removals: usize,
+}
+
+impl Rules {
+    /// Part 1: steps of 1 to 3, nothing removed.
+    pub const STRICT: Rules = Rules {
+        min_step: 1,
+        max_step: 3,
+        removals: 0,
+    };
+
+    /// Part 2: the Problem Dampener may remove one level.
+    pub const DAMPENED: Rules = Rules {
+        removals: 1,
+        ..Rules::STRICT
+    };
+
+    fn fits(&self, from: i32, to: i32, rising: bool) -> bool {
+        let step = if rising { to - from } else { from - to };
+        (self.min_step..=self.max_step).contains(&step)
+    }
+}
+
+/// The fewest levels to remove so the rest all rise (or all fall) by an
+/// allowed step, as their indices in ascending order, if that is no more
+/// than `rules.removals`.
+///
+/// `fewest[i]` is the fewest removals from `levels[..=i]` that keep level `i`,
+/// with the kept level before it. Level `i` follows the last kept level `j`,
+/// and everything in between is removed, so only the `removals + 1` levels
+/// before `i` can be `j`: the whole scan is O(n * k) rather than a try of
+/// every subset.
+fn removals_in_direction(levels: &[i32], rules: &Rules, rising: bool) -> Option<Vec<usize>> {
+    let k = rules.removals;
+    let mut fewest: Vec<Option<(usize, Option<usize>)>> = Vec::with_capacity(levels.len());
+
+    for (i, &level) in levels.iter().enumerate() {
+        // Keeping level i first means removing all of the ones before it
+        let mut best = (i <= k).then_some((i, None));
+        for (j, &previous) in levels
+            .iter()
+            .enumerate()
+            .take(i)
+            .skip(i.saturating_sub(k + 1))
+        {
+            if let Some((before, _)) = fewest[j] {
+                let removed = before + (i - j - 1);
+                if removed <= k
+                    && rules.fits(previous, level, rising)
+                    && best.is_none_or(|(b, _)| removed < b)
+                {
+                    best = Some((removed, Some(j)));
+                }
+            }
+        }
+        fewest.push(best);
+    }
+
+    // Then everything after the last kept level goes
+    let last = levels.len() - 1;
+    let (_, end) = fewest
+        .iter()
+        .enumerate()
+        .filter_map(|(i, best)| best.map(|(removed, _)| (removed + (last - i), i)))
+        .filter(|&(removed, _)| removed <= k)
+        .min()?;
+
+    let mut kept = vec![false; levels.len()];
+    let mut at = Some(end);
+    while let Some(i) = at {
+        kept[i] = true;
+        at = fewest[i].and_then(|(_, previous)| previous);
+    }
+    Some((0..levels.len()).filter(|&i| !kept[i]).collect())
+}
+
+/// The indices of the fewest levels `rules` needs removed to make the report
+/// safe, or `None` if it takes more than `rules.removals`. Where rising and
+/// falling need as many, the rising removals are given.
+pub fn removal_plan(levels: &[i32], rules: &Rules) -> Option<Vec<usize>> {
+    if levels.is_empty() {
+        return Some(Vec::new());
+    }
+    let rising = removals_in_direction(levels, rules, true);
+    let falling = removals_in_direction(levels, rules, false);
+    rising.into_iter().chain(falling).min_by_key(Vec::len)
+}
+
+/// How many levels `removal_plan` removes.
+pub fn removals_needed(levels: &[i32], rules: &Rules) -> Option<usize> {
+    removal_plan(levels, rules).map(|plan| plan.len())
+}
+
+pub fn is_safe(levels: &[i32], rules: &Rules) -> bool {
+    removal_plan(levels, rules).is_some()
+}
+
+/// Why a step between two neighbouring levels breaks the rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ViolationKind {
+    /// The step goes the other way from the first step.
+    DirectionFlip,
+    /// Two equal levels in a row.
+    ZeroDelta,
+    /// A step smaller than `min_step`, other than zero.
+    StepTooSmall,
+    StepTooLarge,
+}
+
+impl ViolationKind {
+    fn name(self) -> &'static str {
+        match self {
+            ViolationKind::DirectionFlip => "direction_flip",
+            ViolationKind::ZeroDelta => "zero_delta",
+            ViolationKind::StepTooSmall => "step_too_small",
+            ViolationKind::StepTooLarge => "step_too_large",
+        }
+    }
+}
+
+/// The first step of a report, as given, that breaks the rules. `index` is
+/// the level the step arrives at, counting from 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Violation {
+    pub index: usize,
+    pub kind: ViolationKind,
+}
+
+/// Size is checked before direction, and the first step sets the direction.
+pub fn first_violation(levels: &[i32], rules: &Rules) -> Option<Violation> {
+    let mut rising = None;
+    for (i, pair) in levels.windows(2).enumerate() {
+        let delta = pair[1] - pair[0];
+        let kind = if delta == 0 && rules.min_step > 0 {
+            Some(ViolationKind::ZeroDelta)
+        } else if delta.abs() > rules.max_step {
+            Some(ViolationKind::StepTooLarge)
+        } else if delta.abs() < rules.min_step {
+            Some(ViolationKind::StepTooSmall)
+        } else if delta != 0 && *rising.get_or_insert(delta > 0) != (delta > 0) {
+            Some(ViolationKind::DirectionFlip)
+        } else {
+            None
+        };
+        if let Some(kind) = kind {
+            return Some(Violation { index: i + 1, kind });
+        }
+    }
+    None
+}
+
+/// A level taken out of a report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Removal {
+    pub index: usize,
+    pub level: i32,
+}
+
+/// Everything checking one report found.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Diagnostic {
+    /// 1-based, in input order.
+    pub report: usize,
+    pub safe: bool,
+    /// The first broken step before anything is removed.
+    pub violation: Option<Violation>,
+    /// The levels removed to make it safe; empty when it is safe as it is
+    /// or cannot be made safe.
+    pub removed: Vec<Removal>,
+}
+
+pub fn diagnose(report: usize, levels: &[i32], rules: &Rules) -> Diagnostic {
+    let plan = removal_plan(levels, rules);
+    Diagnostic {
+        report,
+        safe: plan.is_some(),
+        violation: first_violation(levels, rules),
+        removed: plan
+            .unwrap_or_default()
+            .into_iter()
+            .map(|index| Removal {
+                index,
+                level: levels[index],
+            })
+            .collect(),
+    }
+}
+
+/// The diagnostics as a fixed-width table, one report per row.
+pub fn diagnostics_table(diagnostics: &[Diagnostic]) -> String {
+    let mut table = format!(
+        "{:>6}  {:<4}  {:<14}  {:>5}  removed",
+        "report", "safe", "violation", "at"
+    );
+    for d in diagnostics {
+        let (kind, at) = match d.violation {
+            Some(v) => (v.kind.name(), v.index.to_string()),
+            None => ("-", "-".to_string()),
+        };
+        let removed: Vec<String> = d
+            .removed
+            .iter()
+            .map(|r| format!("{} at {}", r.level, r.index))
+            .collect();
+        let removed = match removed.is_empty() {
+            true => "-".to_string(),
+            false => removed.join(", "),
+        };
+        let safe = if d.safe { "yes" } else { "no" };
+        write!(
+            table,
+            "\n{:>6}  {:<4}  {:<14}  {:>5}  {}",
+            d.report, safe, kind, at, removed
+        )
+        .unwrap();
+    }
+    table
+}
+
+fn parse_reports(characters: &[u8]) -> Result<Vec<Vec<i32>>, ParseError> {
+    let mut reports = Vec::new();
+    let mut start_index = 0;
+    let mut line_no = 1;
+
+    while start_index < characters.len() {
+        let (numbers, new_index) = parse_line(start_index, characters, line_no)?;
+        if !numbers.is_empty() {
+            reports.push(numbers);
+        }
+        start_index = new_index + 1;
+        line_no += 1;
+    }
+
+    if reports.is_empty() {
+        return Err(ParseError::end_of_input(line_no, "a report"));
+    }
+    Ok(reports)
+}
+
+pub struct Reports(Vec<Vec<i32>>);
+
+impl Solution for Reports {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        parse_reports(input.as_bytes()).map(Reports)
+    }
+
+    fn part1(&self) -> usize {
+        self.count_safe(&Rules::STRICT)
+    }
+
+    fn part2(&self) -> usize {
+        self.count_safe(&Rules::DAMPENED)
+    }
+}
+
+impl Reports {
+    /// A diagnostic for every report under `rules`.
+    pub fn diagnose(&self, rules: &Rules) -> Vec<Diagnostic> {
+        self.0
+            .iter()
+            .enumerate()
+            .map(|(i, levels)| diagnose(i + 1, levels, rules))
+            .collect()
+    }
+
+    /// How many reports are safe under `rules`.
+    pub fn count_safe(&self, rules: &Rules) -> usize {
+        let reports = &self.0;
+
+        // The full diagnostics only when they will be shown
+        if metrics::enabled() || log::enabled(Level::Trace) {
+            let diagnostics = self.diagnose(rules);
+            trace!("{}", diagnostics_table(&diagnostics));
+            let danger_reps = diagnostics.iter().filter(|d| !d.safe).count();
+            debug!("Total {} Dangerous {}", reports.len(), danger_reps);
+            metrics::record("reports", reports.len());
+            metrics::record("dangerous", danger_reps);
+            metrics::record("diagnostics", &diagnostics);
+            return reports.len() - danger_reps;
+        }
+
+        reports
+            .iter()
+            .filter(|levels| removal_plan(levels, rules).is_some())
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const SAMPLE: &str = "\
+7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9
+";
+
+    #[test]
+    fn sample() {
+        let reports = Reports::parse(SAMPLE).unwrap();
+        assert_eq!(reports.part1(), 2);
+        assert_eq!(reports.part2(), 4);
+    }
+
+    #[test]
+    fn sequences() {
+        let safe = |levels: &[i32]| is_safe(levels, &Rules::STRICT);
+        assert!(safe(&[7, 6, 4, 2, 1]));
+        assert!(safe(&[1, 3, 6, 7, 9]));
+        assert!(safe(&[5]));
+        // Too big a step, a flat step, and a change of direction
+        assert!(!safe(&[1, 2, 7, 8, 9]));
+        assert!(!safe(&[8, 6, 4, 4, 1]));
+        assert!(!safe(&[1, 3, 2, 4, 5]));
+    }
+
+    #[test]
+    fn dampener_removes_one_level() {
+        let needed = |levels: &[i32]| removals_needed(levels, &Rules::DAMPENED);
+        assert_eq!(needed(&[1, 3, 2, 4, 5]), Some(1));
+        assert_eq!(needed(&[8, 6, 4, 4, 1]), Some(1));
+        assert_eq!(needed(&[7, 6, 4, 2, 1]), Some(0));
+        // Removing the first or the last level is allowed too
+        assert_eq!(needed(&[9, 1, 2, 3]), Some(1));
+        assert_eq!(needed(&[1, 2, 3, 9]), Some(1));
+        assert_eq!(needed(&[1, 2, 7, 8, 9]), None);
+    }
+
+    #[test]
+    fn configurable_steps_and_removals() {
+        let levels = [1, 9, 2, 9, 3];
+        let rules = |removals| Rules {
+            removals,
+            ..Rules::STRICT
+        };
+        assert_eq!(removals_needed(&levels, &rules(1)), None);
+        assert_eq!(removals_needed(&levels, &rules(2)), Some(2));
+        assert_eq!(removals_needed(&levels, &rules(4)), Some(2));
+
+        let wide = Rules {
+            min_step: 2,
+            max_step: 8,
+            removals: 0,
+        };
+        assert!(is_safe(&[1, 9, 11, 15], &wide));
+        assert!(!is_safe(&[1, 2, 4], &wide));
+        let flat = Rules {
+            min_step: 0,
+            ..Rules::STRICT
+        };
+        assert!(is_safe(&[8, 6, 4, 4, 1], &flat));
+
+        // A long report with many bad levels stays linear in its length
+        let mut long: Vec<i32> = (0..100_000).collect();
+        for i in (0..long.len()).step_by(1000) {
+            long[i] = -50;
+        }
+        let k = Rules {
+            removals: 100,
+            ..Rules::STRICT
+        };
+        assert_eq!(removals_needed(&long, &k), Some(100));
+    }
+
+    #[test]
+    fn diagnostics() {
+        let reports = Reports::parse(SAMPLE).unwrap();
+        let diagnostics = reports.diagnose(&Rules::DAMPENED);
+        let summary: Vec<_> = diagnostics
+            .iter()
+            .map(|d| {
+                (
+                    d.safe,
+                    d.violation.map(|v| (v.index, v.kind)),
+                    d.removed.clone(),
+                )
+            })
+            .collect();
+        let removal = |index, level| vec![Removal { index, level }];
+        assert_eq!(
+            summary,
+            [
+                (true, None, vec![]),
+                (false, Some((2, ViolationKind::StepTooLarge)), vec![]),
+                (false, Some((3, ViolationKind::StepTooLarge)), vec![]),
+                (true, Some((2, ViolationKind::DirectionFlip)), removal(2, 2)),
+                (true, Some((3, ViolationKind::ZeroDelta)), removal(3, 4)),
+                (true, None, vec![]),
+            ]
+        );
+
+        let table = diagnostics_table(&diagnostics[3..4]);
+        assert_eq!(
+            table,
+            "report  safe  violation          at  removed\n     \
+                  4  yes   direction_flip      2  2 at 2"
+        );
+
+        let narrow = Rules {
+            min_step: 2,
+            ..Rules::STRICT
+        };
+        let violation = first_violation(&[1, 3, 4], &narrow).unwrap();
+        assert_eq!(violation.kind, ViolationKind::StepTooSmall);
+    }
+
+    /// Every way of removing up to `rules.removals` levels, checked one by one.
+    fn removals_brute_force(levels: &[i32], rules: &Rules) -> Option<usize> {
+        let ordered =
+            |kept: &[i32], rising: bool| kept.windows(2).all(|w| rules.fits(w[0], w[1], rising));
+        (0..1u32 << levels.len())
+            .filter(|mask| mask.count_ones() as usize <= rules.removals)
+            .filter(|&mask| {
+                let kept: Vec<i32> = (0..levels.len())
+                    .filter(|i| mask & (1 << i) == 0)
+                    .map(|i| levels[i])
+                    .collect();
+                ordered(&kept, true) || ordered(&kept, false)
+            })
+            .map(|mask| mask.count_ones() as usize)
+            .min()
+    }
+
+    proptest! {
+        #[test]
+        fn removals_match_brute_force(
+            levels in prop::collection::vec(0i32..12, 1..10),
+            min_step in 0i32..3,
+            extra in 0i32..3,
+            removals in 0usize..4,
+        ) {
+            let rules = Rules { min_step, max_step: min_step + extra, removals };
+            prop_assert_eq!(
+                removals_needed(&levels, &rules),
+                removals_brute_force(&levels, &rules)
+            );
+        }
+    }
+}
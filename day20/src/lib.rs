@@ -0,0 +1,191 @@
+use libs::{debug, info, metrics, search, Grid, ParseError, Point, Solution};
+use std::collections::HashMap;
+
+#[derive(Debug, PartialEq, Clone)]
+enum Thing {
+    Empty,
+    Wall,
+    Start,
+    End,
+}
+
+type Map = Grid<Thing>;
+
+#[derive(Debug)]
+struct JumpResult {
+    from: Point,
+    to: Point,
+    total_length: i32,
+}
+
+fn read_map(contents: &str) -> Result<(Map, Point, Point), ParseError> {
+    let grid = Grid::parse_chars(contents, ".#SE")?;
+    let start = grid.find_one('S')?;
+    let end = grid.find_one('E')?;
+
+    let map = grid.map(|&c| match c {
+        '#' => Thing::Wall,
+        'S' => Thing::Start,
+        'E' => Thing::End,
+        _ => Thing::Empty,
+    });
+    Ok((map, start, end))
+}
+
+fn calculate_distances(map: &Map, start: Point) -> HashMap<Point, i32> {
+    let open = |&p: &Point| map.neighbours4(p).filter(|&next| map[next] != Thing::Wall);
+    search::bfs(start, open, |_| false)
+        .into_distances()
+        .into_iter()
+        .map(|(p, distance)| (p, distance as i32))
+        .collect()
+}
+
+fn analyze_jumps(
+    map: &Map,
+    start_distances: &HashMap<Point, i32>,
+    end_distances: &HashMap<Point, i32>,
+    reference_cost: i32,
+    max_jump: i32,
+) -> Vec<JumpResult> {
+    let mut shortcuts = Vec::new();
+
+    for (start_jump, start_thing) in map.iter() {
+        if matches!(start_thing, Thing::Empty | Thing::Start) {
+            // Skip if we can't reach this position from start
+            if !start_distances.contains_key(&start_jump) {
+                continue;
+            }
+
+            // Check all positions within Manhattan distance of max_jump
+            for (end_jump, end_thing) in map.iter() {
+                let manhattan_dist = start_jump.manhattan(end_jump);
+
+                // Skip if jump is too long or to the same position
+                if manhattan_dist == 0 || manhattan_dist > max_jump {
+                    continue;
+                }
+
+                // Skip walls and unreachable positions
+                if matches!(end_thing, Thing::Wall) || !end_distances.contains_key(&end_jump) {
+                    continue;
+                }
+
+                // Calculate total path length with jump
+                let path_length = start_distances[&start_jump] // Start to jump point
+                    + manhattan_dist  // Cost of jump
+                    + end_distances[&end_jump]; // Jump endpoint to end
+
+                if path_length < reference_cost {
+                    shortcuts.push(JumpResult {
+                        from: start_jump,
+                        to: end_jump,
+                        total_length: path_length,
+                    });
+                }
+            }
+        }
+    }
+    shortcuts
+}
+
+/// Count the cheats of at most `max_jump` picoseconds that save at least `min_saving`.
+fn count_shortcuts(track: &Racetrack, max_jump: i32, min_saving: i32) -> usize {
+    let (map, start_pos, end_pos) = (&track.map, track.start, track.end);
+
+    // Calculate distances from start and end
+    let start_distances = calculate_distances(map, start_pos);
+    let end_distances = calculate_distances(map, end_pos);
+
+    // Calculate reference path length (without jumps)
+    let reference_cost = match start_distances.get(&end_pos) {
+        Some(cost) => *cost,
+        None => {
+            info!("No path found from start to end!");
+            return 0;
+        }
+    };
+
+    debug!("Reference path length: {}", reference_cost);
+
+    // Analyze possible jumps
+    let shortcuts = analyze_jumps(
+        map,
+        &start_distances,
+        &end_distances,
+        reference_cost,
+        max_jump,
+    );
+
+    let count = shortcuts
+        .iter()
+        .filter(|shortcut| reference_cost - shortcut.total_length >= min_saving)
+        .count();
+
+    debug!("\nFound {} good shortcuts", count);
+    metrics::record("reference_cost", reference_cost);
+    if metrics::enabled() {
+        // Each shortcut as [from, to, picoseconds saved]
+        let good: Vec<_> = shortcuts
+            .iter()
+            .map(|shortcut| {
+                (
+                    (shortcut.from.x, shortcut.from.y),
+                    (shortcut.to.x, shortcut.to.y),
+                    reference_cost - shortcut.total_length,
+                )
+            })
+            .filter(|&(_, _, saving)| saving >= min_saving)
+            .collect();
+        metrics::record("shortcuts", good);
+    }
+    count
+}
+
+pub struct Racetrack {
+    map: Map,
+    start: Point,
+    end: Point,
+}
+
+impl Solution for Racetrack {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (map, start, end) = read_map(input)?;
+        Ok(Racetrack { map, start, end })
+    }
+
+    fn part1(&self) -> usize {
+        count_shortcuts(self, 2, 100)
+    }
+
+    fn part2(&self) -> usize {
+        count_shortcuts(self, 20, 100)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = include_str!("../../inputs/day20/data.txt");
+
+    #[test]
+    fn sample() {
+        let track = Racetrack::parse(SAMPLE).unwrap();
+        // No shortcut on the small example saves the 100 picoseconds the puzzle asks for
+        assert_eq!(track.part1(), 0);
+        assert_eq!(track.part2(), 0);
+    }
+
+    #[test]
+    fn shortcuts_on_the_example() {
+        let track = Racetrack::parse(SAMPLE).unwrap();
+        assert_eq!(count_shortcuts(&track, 2, 1), 44);
+        assert_eq!(count_shortcuts(&track, 2, 64), 1);
+        assert_eq!(count_shortcuts(&track, 20, 50), 285);
+        assert_eq!(count_shortcuts(&track, 20, 76), 3);
+    }
+}
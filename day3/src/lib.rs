@@ -0,0 +1,334 @@
+//! One pass over the corrupted memory: a lexer picks `mul(X,Y)`, `do()` and
+//! `don't()` out of the bytes as they stream in, and an interpreter adds up
+//! the products.
+
+use libs::{debug, metrics, trace, ParseError, Solution};
+use std::io::{self, BufRead, BufReader, Read};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Multiply(u32, u32, u32), // (n, m, result)
+    Do,
+    Dont,
+}
+
+const MUL: &[u8] = b"mul(";
+const DO: &[u8] = b"do()";
+const DONT: &[u8] = b"don't()";
+
+/// Where the lexer is inside a possible command.
+#[derive(Debug, Clone, Copy)]
+enum State {
+    Start,
+    /// The first `matched` bytes of `word` have been seen.
+    Word {
+        word: &'static [u8],
+        matched: usize,
+    },
+    /// After `mul(`, reading the first number, then the second.
+    First {
+        n: u32,
+        digits: usize,
+    },
+    Second {
+        n: u32,
+        m: u32,
+        digits: usize,
+    },
+}
+
+/// The state after `byte` when nothing is in progress. No command has an `m`
+/// or a `d` after its first byte, so a failed match can only restart at the
+/// byte that broke it, and no byte is ever looked at twice.
+fn start(byte: u8) -> State {
+    match byte {
+        b'm' => State::Word {
+            word: MUL,
+            matched: 1,
+        },
+        b'd' => State::Word {
+            word: DO,
+            matched: 1,
+        },
+        _ => State::Start,
+    }
+}
+
+fn digit(byte: u8) -> Option<u32> {
+    byte.is_ascii_digit().then(|| u32::from(byte - b'0'))
+}
+
+fn step(state: State, byte: u8) -> (State, Option<Command>) {
+    match state {
+        State::Start => (start(byte), None),
+        State::Word { word, matched } => {
+            // `do()` and `don't()` part ways after `do`
+            let word = match (word == DO, matched, byte) {
+                (true, 2, b'n') => DONT,
+                _ => word,
+            };
+            if word[matched] != byte {
+                return (start(byte), None);
+            }
+            if matched + 1 < word.len() {
+                return (
+                    State::Word {
+                        word,
+                        matched: matched + 1,
+                    },
+                    None,
+                );
+            }
+            match word {
+                MUL => (State::First { n: 0, digits: 0 }, None),
+                DO => (State::Start, Some(Command::Do)),
+                _ => (State::Start, Some(Command::Dont)),
+            }
+        }
+        State::First { n, digits } => match (digit(byte), byte) {
+            (Some(d), _) if digits < 3 => (
+                State::First {
+                    n: n * 10 + d,
+                    digits: digits + 1,
+                },
+                None,
+            ),
+            (None, b',') if digits > 0 => (State::Second { n, m: 0, digits: 0 }, None),
+            _ => (start(byte), None),
+        },
+        State::Second { n, m, digits } => match (digit(byte), byte) {
+            (Some(d), _) if digits < 3 => (
+                State::Second {
+                    n,
+                    m: m * 10 + d,
+                    digits: digits + 1,
+                },
+                None,
+            ),
+            (None, b')') if digits > 0 => (State::Start, Some(Command::Multiply(n, m, n * m))),
+            _ => (start(byte), None),
+        },
+    }
+}
+
+/// The commands in a stream of bytes, in order, read as they are needed.
+/// Numbers are one to three ASCII digits; everything that is not part of a
+/// complete command is skipped.
+pub struct Lexer<R> {
+    reader: R,
+    state: State,
+}
+
+impl<R: BufRead> Lexer<R> {
+    pub fn new(reader: R) -> Self {
+        Lexer {
+            reader,
+            state: State::Start,
+        }
+    }
+}
+
+impl<R: Read> Lexer<BufReader<R>> {
+    pub fn from_reader(reader: R) -> Self {
+        Lexer::new(BufReader::new(reader))
+    }
+}
+
+impl<R: BufRead> Iterator for Lexer<R> {
+    type Item = io::Result<Command>;
+
+    fn next(&mut self) -> Option<io::Result<Command>> {
+        loop {
+            let buffer = match self.reader.fill_buf() {
+                Ok([]) => return None,
+                Ok(buffer) => buffer,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Some(Err(e)),
+            };
+
+            let mut used = 0;
+            let mut found = None;
+            for &byte in buffer {
+                used += 1;
+                let (state, command) = step(self.state, byte);
+                self.state = state;
+                if command.is_some() {
+                    found = command;
+                    break;
+                }
+            }
+            self.reader.consume(used);
+            if let Some(command) = found {
+                return Some(Ok(command));
+            }
+        }
+    }
+}
+
+/// Runs commands one at a time, keeping both sums: every product, and only
+/// the products while enabled, which `don't()` turns off and `do()` back on.
+#[derive(Debug, Clone)]
+pub struct Interpreter {
+    enabled: bool,
+    commands: usize,
+    total: u64,
+    enabled_total: u64,
+}
+
+impl Default for Interpreter {
+    fn default() -> Self {
+        Interpreter {
+            enabled: true,
+            commands: 0,
+            total: 0,
+            enabled_total: 0,
+        }
+    }
+}
+
+impl Interpreter {
+    pub fn execute(&mut self, command: Command) {
+        self.commands += 1;
+        let i = self.commands;
+        match command {
+            Command::Multiply(n, m, result) => {
+                trace!("Command {}: MUL    {} * {} = {}", i, n, m, result);
+                self.total += u64::from(result);
+                if self.enabled {
+                    self.enabled_total += u64::from(result);
+                }
+            }
+            Command::Do => {
+                trace!("Command {}: DO     do()", i);
+                self.enabled = true;
+            }
+            Command::Dont => {
+                trace!("Command {}: DON'T  don't()", i);
+                self.enabled = false;
+            }
+        }
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Part 1: every product, whatever `do()` and `don't()` said.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Part 2: only the products made while enabled.
+    pub fn enabled_total(&self) -> u64 {
+        self.enabled_total
+    }
+}
+
+/// Both answers straight from a reader, without keeping the commands.
+pub fn run(reader: impl Read) -> io::Result<(u64, u64)> {
+    let mut interpreter = Interpreter::default();
+    for command in Lexer::from_reader(reader) {
+        interpreter.execute(command?);
+    }
+    Ok((interpreter.total(), interpreter.enabled_total()))
+}
+
+pub struct Program(Vec<Command>);
+
+impl Solution for Program {
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        // Reading from a slice cannot fail
+        let commands = Lexer::new(input.as_bytes()).map(Result::unwrap).collect();
+        Ok(Program(commands))
+    }
+
+    fn part1(&self) -> u64 {
+        self.interpret().total()
+    }
+
+    fn part2(&self) -> u64 {
+        self.interpret().enabled_total()
+    }
+}
+
+impl Program {
+    fn interpret(&self) -> Interpreter {
+        let mut interpreter = Interpreter::default();
+        for &command in &self.0 {
+            interpreter.execute(command);
+        }
+        debug!("\nTotal commands found: {}", self.0.len());
+        metrics::record("commands", self.0.len());
+        interpreter
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+    const SAMPLE2: &str =
+        "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+    #[test]
+    fn sample() {
+        assert_eq!(Program::parse(SAMPLE).unwrap().part1(), 161);
+
+        let program = Program::parse(SAMPLE2).unwrap();
+        assert_eq!(program.part1(), 161);
+        assert_eq!(program.part2(), 48);
+    }
+
+    fn lex(input: &str) -> Vec<Command> {
+        Lexer::new(input.as_bytes()).map(Result::unwrap).collect()
+    }
+
+    #[test]
+    fn only_whole_commands() {
+        use Command::*;
+        assert_eq!(lex("mul(1234,5)mul(12,3mul(,3)mul(1,2,3)"), []);
+        assert_eq!(lex("mul ( 2,3)mul(2, 3)MUL(2,3)"), []);
+        // A broken command can be followed straight away by a good one
+        assert_eq!(lex("mumul(2,3)"), [Multiply(2, 3, 6)]);
+        assert_eq!(lex("mul(1mul(4,5)"), [Multiply(4, 5, 20)]);
+        assert_eq!(lex("dodon't()don'do()"), [Dont, Do]);
+        assert_eq!(lex("do(don't()do)"), [Dont]);
+        assert_eq!(
+            lex("mul(999,999)\u{2019}mul(0,0)"),
+            [Multiply(999, 999, 998001), Multiply(0, 0, 0)]
+        );
+    }
+
+    #[test]
+    fn streams_across_reads() {
+        // One byte per read splits every command across reads
+        let reader = BufReader::with_capacity(1, SAMPLE2.as_bytes());
+        let commands: Vec<Command> = Lexer::new(reader).map(Result::unwrap).collect();
+        assert_eq!(commands, lex(SAMPLE2));
+        assert_eq!(commands.len(), 6);
+
+        assert_eq!(run(SAMPLE2.as_bytes()).unwrap(), (161, 48));
+    }
+
+    #[test]
+    fn interpreter_keeps_the_enable_state() {
+        let mut interpreter = Interpreter::default();
+        assert!(interpreter.enabled());
+        for command in lex("don't()mul(2,3)don't()do()mul(1,1)") {
+            interpreter.execute(command);
+        }
+        assert!(interpreter.enabled());
+        assert_eq!((interpreter.total(), interpreter.enabled_total()), (7, 1));
+    }
+
+    #[test]
+    fn large_sums_do_not_overflow() {
+        let input = "mul(999,999)".repeat(10_000);
+        let expected = 998_001 * 10_000;
+        assert_eq!(run(input.as_bytes()).unwrap(), (expected, expected));
+    }
+}
@@ -0,0 +1,115 @@
+use libs::{debug, info, trace, Direction8, Grid, ParseError, Point, Solution};
+
+fn count_word(grid: &Grid<char>, word: &str) -> usize {
+    let letters: Vec<char> = word.chars().collect();
+
+    let mut count = 0;
+    for start in grid.points() {
+        // All eight directions, including backwards and diagonals
+        for direction in Direction8::ALL {
+            let delta = direction.delta();
+            let matches = letters.iter().enumerate().all(|(i, letter)| {
+                let p = start + delta * i as i32;
+                grid.get(p) == Some(letter)
+            });
+            if matches {
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
+
+fn check_direction(grid: &Grid<char>, p: Point, direction: Direction8) -> bool {
+    // First check if current position is 'A'
+    grid[p] == 'A'
+        // Check for 'M' behind us
+        && grid.get(p - direction.delta()) == Some(&'M')
+        // Check for 'S' ahead
+        && grid.get(p + direction.delta()) == Some(&'S')
+}
+
+fn find_xmas(grid: &Grid<char>) -> Vec<(Point, Direction8)> {
+    let mut findings = Vec::new();
+
+    for p in grid.points() {
+        let mut found = false;
+        // Only diagonal directions
+        for direction in Direction8::DIAGONALS {
+            if check_direction(grid, p, direction) {
+                if !found {
+                    found = true;
+                    trace!("++ x: {} y: {}, dir: {:?}", p.x, p.y, direction);
+                } else {
+                    trace!("!! x: {} y: {}, dir: {:?}", p.x, p.y, direction);
+                    findings.push((p, direction));
+                    break;
+                }
+            } else {
+                trace!("-- x: {} y: {}, dir: {:?}", p.x, p.y, direction);
+            }
+        }
+    }
+
+    findings
+}
+
+pub struct WordSearch(Grid<char>);
+
+impl Solution for WordSearch {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Grid::parse_chars(input, "XMAS").map(WordSearch)
+    }
+
+    fn part1(&self) -> usize {
+        count_word(&self.0, "XMAS")
+    }
+
+    fn part2(&self) -> usize {
+        count_crosses(&self.0)
+    }
+}
+
+fn count_crosses(grid: &Grid<char>) -> usize {
+    let findings = find_xmas(grid);
+
+    if findings.is_empty() {
+        info!("No 'XMAS' patterns found!");
+    } else {
+        debug!("Found 'XMAS' at the following positions:");
+        for (p, direction) in &findings {
+            trace!("Position ({}, {}) going {:?}", p.x, p.y, direction);
+        }
+    }
+
+    findings.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX
+";
+
+    #[test]
+    fn sample() {
+        let search = WordSearch::parse(SAMPLE).unwrap();
+        assert_eq!(search.part1(), 18);
+        assert_eq!(search.part2(), 9);
+    }
+}
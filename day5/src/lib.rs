@@ -0,0 +1,199 @@
+use libs::error::{self, ParseError};
+use libs::{metrics, trace, Solution};
+
+/// A line of input together with its 1-based line number.
+type Line<'a> = (usize, &'a str);
+
+/// Split the input into rule and update lines, keeping each line's number.
+fn read_to_array(content: &str) -> (Vec<Line<'_>>, Vec<Line<'_>>) {
+    let mut rules = Vec::new();
+    let mut pages = Vec::new();
+    let mut is_rules = true; // Flag to track which section we're in
+
+    for (idx, line_content) in content.lines().enumerate() {
+        if line_content.trim().is_empty() {
+            is_rules = false; // Switch to pages section after empty line
+            continue; // Skip the empty line itself
+        }
+
+        if is_rules {
+            rules.push((idx + 1, line_content));
+        } else {
+            pages.push((idx + 1, line_content));
+        }
+    }
+
+    (rules, pages)
+}
+
+fn parse_numbers(line_no: usize, line: &str, separator: char) -> Result<Vec<i32>, ParseError> {
+    line.split(separator)
+        .map(|s| error::number(line_no, line, s.trim()))
+        .collect()
+}
+
+fn parse_rules(rules_lines: &[Line]) -> Result<Vec<Vec<i32>>, ParseError> {
+    let mut rules = Vec::new();
+
+    for &(line_no, line) in rules_lines {
+        let numbers = parse_numbers(line_no, line, '|')?;
+        if numbers.len() != 2 {
+            return Err(ParseError::at(line_no, line, line, "a rule like 'X|Y'"));
+        }
+        rules.push(numbers);
+    }
+
+    Ok(rules)
+}
+
+fn parse_pages(pages_lines: &[Line]) -> Result<Vec<Vec<i32>>, ParseError> {
+    let mut pages = Vec::new();
+
+    for &(line_no, line) in pages_lines {
+        pages.push(parse_numbers(line_no, line, ',')?);
+    }
+
+    Ok(pages)
+}
+
+fn check_page_numbers(numbers: &[i32], rules: &[Vec<i32>]) -> (bool, usize, i32, i32) {
+    // Iterate through each number in the list
+    for (i, &curr_page) in numbers.iter().enumerate() {
+        // Look at all subsequent numbers
+        for &checked_page in numbers.iter().skip(i + 1) {
+            // Check against each rule
+            for (rule_idx, rule) in rules.iter().enumerate() {
+                if rule[0] == checked_page && rule[1] == curr_page {
+                    return (false, rule_idx + 1, curr_page, checked_page);
+                }
+            }
+        }
+    }
+
+    (true, 0, 0, 0)
+}
+
+fn fix_order(page_list: &[i32], rules: &[Vec<i32>]) -> Vec<i32> {
+    let mut fixed_list = page_list.to_vec();
+
+    'outer: loop {
+        // Create a vector of indices and values
+        let numbers: Vec<(usize, i32)> = fixed_list
+            .iter()
+            .enumerate()
+            .map(|(i, &n)| (i, n))
+            .collect();
+
+        for i in 0..numbers.len() {
+            for j in (i + 1)..numbers.len() {
+                let curr_page = numbers[i].1;
+                let checked_page = numbers[j].1;
+
+                // Check against each rule
+                for rule in rules.iter() {
+                    if rule[0] == checked_page && rule[1] == curr_page {
+                        // Swap the numbers
+                        fixed_list.swap(numbers[i].0, numbers[j].0);
+                        continue 'outer;
+                    }
+                }
+            }
+        }
+
+        // If we get here, no violations were found
+        break;
+    }
+
+    fixed_list
+}
+
+pub struct PrintQueue {
+    rules: Vec<Vec<i32>>,
+    pages: Vec<Vec<i32>>,
+}
+
+impl Solution for PrintQueue {
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (rules_lines, pages_lines) = read_to_array(input);
+        if pages_lines.is_empty() {
+            let line = input.lines().count() + 1;
+            return Err(ParseError::end_of_input(
+                line,
+                "a blank line and the updates",
+            ));
+        }
+        Ok(PrintQueue {
+            rules: parse_rules(&rules_lines)?,
+            pages: parse_pages(&pages_lines)?,
+        })
+    }
+
+    /// Sum of the middle pages of the updates that are already in order.
+    fn part1(&self) -> i64 {
+        let mut sum = 0;
+        let mut correct = 0;
+        for page_list in &self.pages {
+            let (pass, _, _, _) = check_page_numbers(page_list, &self.rules);
+            if pass {
+                let middle_index = page_list.len() / 2;
+                trace!("mid {}", page_list[middle_index]);
+                sum += i64::from(page_list[middle_index]);
+                correct += 1;
+            }
+        }
+        metrics::record("updates", self.pages.len());
+        metrics::record("correct_updates", correct);
+        sum
+    }
+
+    /// Sum of the middle pages of the out-of-order updates once fixed.
+    fn part2(&self) -> i64 {
+        let mut fix_sum = 0;
+        let mut fixed = 0;
+        for (idx, page_list) in self.pages.iter().enumerate() {
+            let (pass, rule_index, curr_page, checked_page) =
+                check_page_numbers(page_list, &self.rules);
+            if !pass {
+                trace!(
+                    "failed line {}, failed rule {} - {}, {}",
+                    idx + 1,
+                    rule_index + 1,
+                    curr_page,
+                    checked_page
+                );
+                let fixed_list = fix_order(page_list, &self.rules);
+                trace!("Fixed line: {:?}", fixed_list);
+                let middle_index = fixed_list.len() / 2;
+                fix_sum += i64::from(fixed_list[middle_index]);
+                fixed += 1;
+            }
+        }
+        metrics::record("updates", self.pages.len());
+        metrics::record("fixed_updates", fixed);
+        fix_sum
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = include_str!("../../inputs/day05/sample.txt");
+
+    #[test]
+    fn sample() {
+        let queue = PrintQueue::parse(SAMPLE).unwrap();
+        assert_eq!(queue.part1(), 143);
+        assert_eq!(queue.part2(), 123);
+    }
+
+    #[test]
+    fn sums_past_i32() {
+        let queue = PrintQueue::parse("1|2\n\n2000000000\n2000000000\n2,2000000000,1\n").unwrap();
+        assert_eq!(queue.part1(), 4_000_000_000);
+        assert_eq!(queue.part2(), 2_000_000_000);
+    }
+}
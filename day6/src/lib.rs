@@ -0,0 +1,203 @@
+use libs::{debug, info, trace, Direction, Grid, ParseError, Point, Solution};
+use std::collections::HashSet;
+
+#[derive(Debug)]
+enum WalkResult {
+    ExitGrid,
+    Loop,
+}
+
+/// Follow the guard from `start` until they leave the grid or come back to a
+/// position and heading already seen, which is what a loop is. Turning counts
+/// as a move, so a guard boxed in by walls is caught turning in place.
+fn patrol(grid: &Grid<char>, start: Point) -> (HashSet<(Point, Direction)>, WalkResult) {
+    let mut seen = HashSet::new();
+    let mut current = start;
+    let mut direction = Direction::North;
+
+    while seen.insert((current, direction)) {
+        let next = current.step(direction);
+        match grid.get(next) {
+            None => return (seen, WalkResult::ExitGrid),
+            Some('#') => direction = direction.turn_right(),
+            Some(_) => current = next,
+        }
+    }
+    (seen, WalkResult::Loop)
+}
+
+fn walk_grid(grid: &Grid<char>, start: Point) -> HashSet<Point> {
+    let (seen, _) = patrol(grid, start);
+    seen.into_iter().map(|(p, _)| p).collect()
+}
+
+fn walk_grid_detect_loop(grid: &Grid<char>, start: Point) -> WalkResult {
+    patrol(grid, start).1
+}
+
+fn find_loops(grid: &mut Grid<char>, start: Point) -> usize {
+    let mut loop_count = 0;
+
+    // A wall anywhere the guard never goes cannot change the walk
+    for p in walk_grid(grid, start) {
+        // Skip if not a dot or if it's the starting position
+        if grid[p] != '.' || p == start {
+            continue;
+        }
+
+        // Try placing a wall here
+        grid[p] = '#';
+
+        // Check if this creates a loop
+        match walk_grid_detect_loop(grid, start) {
+            WalkResult::Loop => {
+                loop_count += 1;
+                trace!("Found loop with wall at ({}, {})", p.y, p.x);
+            }
+            WalkResult::ExitGrid => {}
+        }
+
+        // Restore the dot
+        grid[p] = '.';
+    }
+
+    loop_count
+}
+
+pub struct Lab {
+    grid: Grid<char>,
+    start: Option<Point>,
+}
+
+impl Solution for Lab {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse_chars(input, ".#^")?;
+        let start = grid.find(&'^');
+        Ok(Lab { grid, start })
+    }
+
+    fn part1(&self) -> usize {
+        match self.start {
+            Some(start) => walk_grid(&self.grid, start).len(),
+            None => {
+                info!("\nNo cursor (^) found in the grid!");
+                0
+            }
+        }
+    }
+
+    fn part2(&self) -> usize {
+        trace!("Successfully read the grid:");
+        trace!("{}", self.grid);
+
+        if let Some(start) = self.start {
+            debug!(
+                "\nFound cursor (^) at position: row {}, column {}",
+                start.y + 1,
+                start.x + 1
+            );
+            let mut grid_copy = self.grid.clone();
+            find_loops(&mut grid_copy, start)
+        } else {
+            info!("\nNo cursor (^) found in the grid!");
+            0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Walk until leaving the grid or running out of moves. There are only
+    /// four headings at each position, so a guard still inside after that
+    /// many moves has been somewhere twice and is going round for good.
+    fn loops_by_step_limit(grid: &Grid<char>, start: Point) -> bool {
+        let mut current = start;
+        let mut direction = Direction::North;
+
+        for _ in 0..=4 * grid.width() * grid.height() {
+            let next = current.step(direction);
+            match grid.get(next) {
+                None => return false,
+                Some('#') => direction = direction.turn_right(),
+                Some(_) => current = next,
+            }
+        }
+        true
+    }
+
+    /// A small lab with random obstacles and the guard somewhere free.
+    fn lab() -> impl Strategy<Value = (Grid<char>, Point)> {
+        (1usize..12, 1usize..12)
+            .prop_flat_map(|(width, height)| {
+                let cells = width * height;
+                (
+                    Just(width),
+                    prop::collection::vec(prop::bool::weighted(0.3), cells),
+                    0..cells,
+                )
+            })
+            .prop_map(|(width, walls, start)| {
+                let mut grid = Grid::new(width, walls.len() / width, '.');
+                let points: Vec<Point> = grid.points().collect();
+                for (&p, &wall) in points.iter().zip(&walls) {
+                    if wall {
+                        grid[p] = '#';
+                    }
+                }
+                grid[points[start]] = '^';
+                (grid, points[start])
+            })
+    }
+
+    const SAMPLE: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+";
+
+    #[test]
+    fn sample() {
+        let lab = Lab::parse(SAMPLE).unwrap();
+        assert_eq!(lab.part1(), 41);
+        assert_eq!(lab.part2(), 6);
+    }
+
+    #[test]
+    fn boxed_in_guard() {
+        // Walls on all four sides: the guard can only turn
+        let boxed = Lab::parse(".#.\n#^#\n.#.\n").unwrap();
+        assert!(matches!(
+            walk_grid_detect_loop(&boxed.grid, Point::new(1, 1)),
+            WalkResult::Loop
+        ));
+        assert_eq!(boxed.part1(), 1);
+        // Already stuck, and new obstacles only count where the guard goes
+        assert_eq!(boxed.part2(), 0);
+
+        // One way out, which a new obstacle closes
+        let open = Lab::parse(".#.\n.^#\n.#.\n").unwrap();
+        assert_eq!(open.part1(), 2);
+        assert_eq!(open.part2(), 1);
+    }
+
+    proptest! {
+        #[test]
+        fn loop_detection_matches_step_limit((grid, start) in lab()) {
+            let detected = matches!(walk_grid_detect_loop(&grid, start), WalkResult::Loop);
+            prop_assert_eq!(detected, loops_by_step_limit(&grid, start), "{}", grid);
+        }
+    }
+}
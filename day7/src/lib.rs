@@ -0,0 +1,153 @@
+use libs::error::{self, ParseError};
+use libs::{debug, metrics, trace, Solution};
+
+#[derive(Debug)]
+struct Entry {
+    answer: u64,
+    numbers: Vec<u64>,
+}
+
+fn parse_line(line_no: usize, line: &str) -> Result<Entry, ParseError> {
+    let Some((answer, rest)) = line.split_once(':') else {
+        return Err(ParseError::at(line_no, line, line, "'<answer>: <numbers>'"));
+    };
+
+    let answer = error::number(line_no, line, answer.trim())?;
+    let mut numbers = Vec::new();
+    for token in rest.split_whitespace() {
+        // Zero is out so that no operator can make the running value smaller
+        match error::number(line_no, line, token)? {
+            0 => return Err(ParseError::at(line_no, line, token, "a positive number")),
+            number => numbers.push(number),
+        }
+    }
+    if numbers.is_empty() {
+        return Err(ParseError::at(
+            line_no,
+            line,
+            &rest[rest.len()..],
+            "at least one number",
+        ));
+    }
+
+    Ok(Entry { answer, numbers })
+}
+
+/// `left` with the digits of `right` written after it, unless that overflows.
+fn concat(left: u64, right: u64) -> Option<u64> {
+    let digits = right.checked_ilog10().unwrap_or(0) + 1;
+    left.checked_mul(10_u64.checked_pow(digits)?)?
+        .checked_add(right)
+}
+
+/// Whether some mix of the first `operators` operators (add, multiply, concat)
+/// takes `value` through the remaining `numbers` to `answer`. A mix that
+/// overflows reaches nothing.
+fn try_combinations(numbers: &[u64], value: u64, answer: u64, operators: usize) -> bool {
+    let Some((&next, rest)) = numbers.split_first() else {
+        return value == answer;
+    };
+    // Every operator keeps the value at least as large, so it cannot come back down
+    if value > answer {
+        return false;
+    }
+
+    let results = [
+        value.checked_add(next),
+        value.checked_mul(next),
+        concat(value, next),
+    ];
+    results[..operators]
+        .iter()
+        .flatten()
+        .any(|&result| try_combinations(rest, result, answer, operators))
+}
+
+fn calibration_sum(entries: &[Entry], operators: usize) -> i128 {
+    let mut sum = 0;
+    let mut solvable = 0;
+    for entry in entries {
+        let (&first, rest) = entry.numbers.split_first().unwrap();
+        if try_combinations(rest, first, entry.answer, operators) {
+            trace!("Testing {}: Victory!", entry.answer);
+            sum += i128::from(entry.answer);
+            solvable += 1;
+        } else {
+            trace!("Testing {}: Failure!", entry.answer);
+        }
+    }
+    debug!("Done with {}", sum);
+    metrics::record("equations", entries.len());
+    metrics::record("solvable", solvable);
+    sum
+}
+
+pub struct Equations(Vec<Entry>);
+
+impl Solution for Equations {
+    type Answer1 = i128;
+    type Answer2 = i128;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(idx, line)| parse_line(idx + 1, line))
+            .collect::<Result<_, _>>()
+            .map(Equations)
+    }
+
+    fn part1(&self) -> i128 {
+        calibration_sum(&self.0, 2)
+    }
+
+    fn part2(&self) -> i128 {
+        calibration_sum(&self.0, 3)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20
+";
+
+    #[test]
+    fn sample() {
+        let equations = Equations::parse(SAMPLE).unwrap();
+        assert_eq!(equations.part1(), 3749);
+        assert_eq!(equations.part2(), 11387);
+    }
+
+    #[test]
+    fn extreme_values() {
+        // Every operator overflows a u64 here
+        let max = u64::MAX;
+        let equations = Equations::parse(&format!("{max}: {max} {max}\n")).unwrap();
+        assert_eq!(equations.part1(), 0);
+        assert_eq!(equations.part2(), 0);
+
+        // Far more operator mixes than fit in any integer
+        let ones = "1 ".repeat(200);
+        let equations = Equations::parse(&format!("1: {ones}\n2: {ones}\n")).unwrap();
+        assert_eq!(equations.part1(), 3);
+        assert_eq!(equations.part2(), 3);
+
+        assert_eq!(
+            Equations::parse("3: 1 0 3\n").err().unwrap().to_string(),
+            "1:6: expected a positive number, found '0'"
+        );
+        assert!(Equations::parse("3: -1 3\n").is_err());
+    }
+}
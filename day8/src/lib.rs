@@ -0,0 +1,174 @@
+use libs::viz::{self, Cell, Color, Frame};
+use libs::{debug, trace, Grid, ParseError, Point, Solution};
+use std::collections::HashSet;
+
+fn get_unique_symbols(grid: &Grid<char>, filter_symbols: &Option<&HashSet<char>>) -> HashSet<char> {
+    grid.iter()
+        .map(|(_, &c)| c)
+        .filter(|&c| c != '.')
+        .filter(|c| filter_symbols.is_none_or(|fs| fs.contains(c)))
+        .collect()
+}
+
+fn visualize_grid(grid: &Grid<char>, points: &[Point], filter_symbols: &Option<&HashSet<char>>) {
+    if !viz::enabled() {
+        return;
+    }
+
+    let shown = Frame::new(grid, |&c| {
+        if c != '.' && filter_symbols.is_none_or(|fs| fs.contains(&c)) {
+            Cell::from((c, Color::Green))
+        } else {
+            Cell::from('.')
+        }
+    });
+    let frame = shown
+        .mark(points, '#', Color::Red)
+        .caption("Antinodes as #, antennas shown as-is");
+    viz::show(&frame);
+}
+
+/// Count antinodes. With `harmonics`, every grid point in line with two antennas counts,
+/// otherwise only the points one antenna-distance beyond each pair.
+fn find_symbol_pairs(
+    grid: &Grid<char>,
+    filter_symbols: &Option<&HashSet<char>>,
+    harmonics: bool,
+) -> usize {
+    // Set to store unique valid extended points
+    let mut unique_extended_points = HashSet::new();
+
+    debug!("Grid size: {}x{}", grid.width(), grid.height());
+
+    // Get unique symbols
+    let symbols = get_unique_symbols(grid, filter_symbols);
+
+    // Print the symbols being processed
+    let mut symbol_vec: Vec<_> = symbols.iter().collect();
+    symbol_vec.sort(); // Sort for consistent output
+    debug!(
+        "Processing symbols: {}",
+        symbol_vec
+            .iter()
+            .map(|symbol| symbol.to_string())
+            .collect::<Vec<_>>()
+            .join(" ")
+    );
+
+    // For each symbol, find all positions
+    for symbol in symbols {
+        let positions: Vec<Point> = grid.find_all(&symbol).collect();
+
+        // Print pairs for this symbol and their extended points
+        trace!("\nPairs for symbol '{}':", symbol);
+        for i in 0..positions.len() {
+            for j in i + 1..positions.len() {
+                let p1 = positions[i];
+                let p2 = positions[j];
+
+                // Vector from the first antenna to the second
+                let delta = p2 - p1;
+
+                // With harmonics the antenna positions themselves are antinodes
+                if harmonics {
+                    unique_extended_points.insert(p1);
+                    unique_extended_points.insert(p2);
+                }
+
+                // Generate all points along the line in both directions
+                let mut multiplier = 1;
+                // Forward direction
+                loop {
+                    let point = p2 + delta * multiplier;
+                    if !grid.contains(point) {
+                        break;
+                    }
+                    unique_extended_points.insert(point);
+                    if !harmonics {
+                        break;
+                    }
+                    multiplier += 1;
+                }
+
+                // Backward direction
+                multiplier = 1;
+                loop {
+                    let point = p1 - delta * multiplier;
+                    if !grid.contains(point) {
+                        break;
+                    }
+                    unique_extended_points.insert(point);
+                    if !harmonics {
+                        break;
+                    }
+                    multiplier += 1;
+                }
+            }
+        }
+    }
+
+    trace!("\nUnique valid extended points:");
+    let mut points: Vec<_> = unique_extended_points.into_iter().collect();
+    points.sort(); // Sort for consistent output
+    for point in &points {
+        trace!("({}, {})", point.x, point.y);
+    }
+
+    visualize_grid(grid, &points, filter_symbols);
+
+    debug!("\nTotal unique valid extended points: {}", points.len());
+    points.len()
+}
+
+/// Antennas are letters or digits; everything else on the map must be '.'.
+fn parse_grid(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input, "'.' or an antenna", |ch| {
+        (ch == '.' || ch.is_ascii_alphanumeric()).then_some(ch)
+    })
+}
+
+pub struct Antennas(Grid<char>);
+
+impl Solution for Antennas {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        parse_grid(input).map(Antennas)
+    }
+
+    fn part1(&self) -> usize {
+        find_symbol_pairs(&self.0, &None, false)
+    }
+
+    fn part2(&self) -> usize {
+        find_symbol_pairs(&self.0, &None, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............
+";
+
+    #[test]
+    fn sample() {
+        let antennas = Antennas::parse(SAMPLE).unwrap();
+        assert_eq!(antennas.part1(), 14);
+        assert_eq!(antennas.part2(), 34);
+    }
+}
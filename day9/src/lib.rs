@@ -0,0 +1,247 @@
+use libs::error::{self, ParseError};
+use libs::{debug, trace, Solution, Variant};
+
+/// Expand the disk map into one entry per block: the file ID, or -1 for free space.
+fn parse_memory_layout(input: &str) -> Result<Vec<i32>, ParseError> {
+    let mut memory_map = Vec::new();
+    let mut current_id = 0;
+    let mut is_block = true; // alternating flag
+
+    let mut numbers: Vec<u32> = Vec::new();
+    for (idx, line) in input.lines().enumerate() {
+        for (pos, ch) in line.trim_end().chars().enumerate() {
+            let digit = ch
+                .to_digit(10)
+                .ok_or_else(|| error::bad_char(idx + 1, pos, ch, "a digit"))?;
+            numbers.push(digit);
+        }
+    }
+    if numbers.is_empty() {
+        return Err(ParseError::end_of_input(1, "a disk map"));
+    }
+
+    for &length in numbers.iter() {
+        if is_block {
+            // Fill memory map
+            for _ in 0..length {
+                memory_map.push(current_id);
+            }
+            current_id += 1;
+        } else {
+            // Fill free space
+            for _ in 0..length {
+                memory_map.push(-1);
+            }
+        }
+        is_block = !is_block; // toggle flag
+    }
+    Ok(memory_map)
+}
+
+fn compactify(memory_map: &mut Vec<i32>) {
+    let mut front = 0;
+
+    loop {
+        // Drop free space off the end, so the last block is always a file
+        while memory_map.last() == Some(&-1) {
+            memory_map.pop();
+        }
+        while front < memory_map.len() && memory_map[front] != -1 {
+            front += 1;
+        }
+        if front == memory_map.len() {
+            break;
+        }
+        // The gap comes before the file at the end, so it survives the pop
+        let last = memory_map.pop().expect("a gap implies a file after it");
+        memory_map[front] = last;
+    }
+}
+
+fn compactify2(memory_map: &mut [i32]) {
+    // A disk map of zero-length files has nothing to move
+    let Some(mut back) = memory_map.len().checked_sub(1) else {
+        return;
+    };
+
+    while back > 0 {
+        // Skip -1s at the end
+        while back > 0 && memory_map[back] == -1 {
+            back -= 1;
+        }
+
+        let value = memory_map[back];
+        let mut block_len = 0;
+        let mut pos = back;
+
+        // Get block size
+        while memory_map[pos] == value {
+            block_len += 1;
+            if pos == 0 {
+                break;
+            }
+
+            pos -= 1;
+        }
+
+        // Look for first gap of sufficient size
+        let mut found_gap = false;
+        let mut front = 0;
+        while front < back {
+            if memory_map[front] == -1 {
+                // Count consecutive -1s
+                let mut gap_len = 0;
+                let mut i = front;
+                while i < memory_map.len() && memory_map[i] == -1 {
+                    gap_len += 1;
+                    i += 1;
+                }
+
+                // gap is big enough
+                if gap_len >= block_len {
+                    // Copy to the front
+                    for i in 0..block_len {
+                        memory_map[front + i] = memory_map[back - i];
+                        memory_map[back - i] = -1;
+                    }
+                    found_gap = true;
+                    break;
+                }
+            }
+            front += 1;
+        }
+
+        // Move back to before the current block
+        if found_gap {
+            back -= block_len;
+        } else {
+            // If no gap found
+            back = pos;
+        }
+    }
+}
+
+fn checksum(memory_map: &[i32]) -> u128 {
+    let mut sum: u128 = 0;
+    for (pos, &value) in memory_map.iter().enumerate() {
+        if value == -1 {
+            continue;
+        }
+        sum += (pos as u128) * (value as u128);
+    }
+    sum
+}
+
+pub struct DiskMap(Vec<i32>);
+
+impl Solution for DiskMap {
+    type Answer1 = u128;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        parse_memory_layout(input).map(DiskMap)
+    }
+
+    fn part1(&self) -> u128 {
+        compact_blocks(&self.0)
+    }
+
+    fn part2(&self) -> u128 {
+        compact_files(&self.0)
+    }
+
+    fn variants() -> Vec<Variant<Self>> {
+        vec![
+            ("compactify", |disk| compact_blocks(&disk.0).to_string()),
+            ("compactify2", |disk| compact_files(&disk.0).to_string()),
+        ]
+    }
+}
+
+fn compact_blocks(memory_map: &[i32]) -> u128 {
+    let mut one_memory_map = memory_map.to_vec();
+
+    for entry in memory_map {
+        trace!("{}", entry);
+    }
+    debug!("Started with {}", one_memory_map.len());
+    compactify(&mut one_memory_map);
+    debug!("Ended with {}", one_memory_map.len());
+
+    if one_memory_map.contains(&-1) {
+        unreachable!("compactify drops trailing gaps and fills every other one");
+    }
+    checksum(&one_memory_map)
+}
+
+fn compact_files(memory_map: &[i32]) -> u128 {
+    let mut two_memory_map = memory_map.to_vec();
+
+    debug!("Started with {}", two_memory_map.len());
+    compactify2(&mut two_memory_map);
+    debug!("Ended with {}", two_memory_map.len());
+
+    checksum(&two_memory_map)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Move the last file block into the leftmost gap, one block at a time,
+    /// until no gap is left in front of a file block.
+    fn compactify_naive(memory_map: &[i32]) -> Vec<i32> {
+        let mut map = memory_map.to_vec();
+        while let Some(last) = map.iter().rposition(|&id| id != -1) {
+            match map[..last].iter().position(|&id| id == -1) {
+                Some(gap) => map.swap(gap, last),
+                None => break,
+            }
+        }
+        while map.last() == Some(&-1) {
+            map.pop();
+        }
+        map
+    }
+
+    /// A disk map as the puzzle writes it: files of 1 to 9 blocks with gaps
+    /// of 0 to 9 blocks between them.
+    fn disk_map() -> impl Strategy<Value = String> {
+        prop::collection::vec((1u32..=9, 0u32..=9), 1..20).prop_map(|files| {
+            files
+                .iter()
+                .flat_map(|&(file, gap)| [file, gap])
+                .map(|digit| char::from_digit(digit, 10).unwrap())
+                .collect()
+        })
+    }
+
+    const SAMPLE: &str = "2333133121414131402\n";
+
+    #[test]
+    fn sample() {
+        let disk = DiskMap::parse(SAMPLE).unwrap();
+        assert_eq!(disk.part1(), 1928);
+        assert_eq!(disk.part2(), 2858);
+    }
+
+    #[test]
+    fn empty_disk() {
+        for input in ["0\n", "05\n", "000\n"] {
+            let disk = DiskMap::parse(input).unwrap();
+            assert_eq!(disk.part1(), 0, "{:?}", input);
+            assert_eq!(disk.part2(), 0, "{:?}", input);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn compactify_matches_naive(input in disk_map()) {
+            let memory_map = parse_memory_layout(&input).unwrap();
+            let mut compacted = memory_map.clone();
+            compactify(&mut compacted);
+            prop_assert_eq!(compacted, compactify_naive(&memory_map));
+        }
+    }
+}
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use libs::Solution;
+
+fuzz_target!(|input: &str| {
+    let _ = day1::Lists::parse(input);
+});
@@ -0,0 +1,16 @@
+#!/bin/sh
+# Seed each target's corpus with the puzzle inputs stored for that day:
+# inputs/day07/*.txt goes to fuzz/corpus/day07/, the corpus of target day07.
+set -e
+cd "$(dirname "$0")"
+for dir in ../inputs/day*/; do
+    day=$(basename "$dir")
+    mkdir -p "corpus/$day"
+    for file in "$dir"*.txt; do
+        # Empty files add nothing to a corpus
+        if [ -s "$file" ]; then
+            cp "$file" "corpus/$day/"
+        fi
+    done
+done
+echo "Seeded $(ls corpus | wc -l) corpora under fuzz/corpus/"
@@ -0,0 +1,108 @@
+//! The error every day's parser returns for malformed input.
+
+use std::fmt;
+use std::str::FromStr;
+
+/// Where parsing stopped and what it wanted to see there.
+///
+/// Lines and columns are 1-based. The file name is unknown to the solvers
+/// and is filled in by whoever read the input, via [`ParseError::in_file`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        expected: impl Into<String>,
+        found: impl Into<String>,
+    ) -> Self {
+        ParseError {
+            file: None,
+            line,
+            column,
+            expected: expected.into(),
+            found: found.into(),
+        }
+    }
+
+    /// Error about `token`, which must be a slice of `text`, on line `line`.
+    pub fn at(line: usize, text: &str, token: &str, expected: impl Into<String>) -> Self {
+        let found = if token.is_empty() {
+            "nothing".to_string()
+        } else {
+            format!("'{}'", token)
+        };
+        ParseError::new(line, column(text, token), expected, found)
+    }
+
+    /// The input ran out on line `line` while `expected` was still missing.
+    pub fn end_of_input(line: usize, expected: impl Into<String>) -> Self {
+        ParseError::new(line, 1, expected, "end of input")
+    }
+
+    pub fn in_file(mut self, file: impl Into<String>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file)?;
+        }
+        write!(
+            f,
+            "{}:{}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// 1-based column of `token` inside `text`. `token` must be a slice of
+/// `text`; anything else is reported as column 1.
+pub fn column(text: &str, token: &str) -> usize {
+    let offset = (token.as_ptr() as usize).wrapping_sub(text.as_ptr() as usize);
+    text.get(..offset)
+        .map_or(1, |before| before.chars().count() + 1)
+}
+
+/// Parse `token` (a slice of `text`, on line `line`) as a number.
+pub fn number<T: FromStr>(line: usize, text: &str, token: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(line, text, token, "a number"))
+}
+
+/// Error for an unexpected character at 0-based `index` of `text` on line `line`.
+pub fn bad_char(line: usize, index: usize, ch: char, expected: impl Into<String>) -> ParseError {
+    ParseError::new(line, index + 1, expected, format!("'{}'", ch))
+}
+
+/// Read a run of ASCII digits as a number; `column` is where the run starts (1-based).
+pub fn parse_digits(digits: &[u8], line: usize, column: usize) -> Result<i32, ParseError> {
+    if digits.is_empty() {
+        return Err(ParseError::new(line, column, "a number", "nothing"));
+    }
+
+    let mut number: i32 = 0;
+    for (offset, &c) in digits.iter().enumerate() {
+        if !c.is_ascii_digit() {
+            return Err(bad_char(line, column - 1 + offset, c as char, "a digit"));
+        }
+        number = number
+            .checked_mul(10)
+            .and_then(|n| n.checked_add((c - b'0') as i32))
+            .ok_or_else(|| ParseError::new(line, column, "a 32-bit number", "a larger one"))?;
+    }
+    Ok(number)
+}
@@ -0,0 +1,263 @@
+//! Points, vectors and compass directions on a grid.
+//!
+//! One convention everywhere: `x` is the column and grows to the right, `y`
+//! is the row and grows downwards, so north is `y - 1`.
+
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+
+/// A position on a grid. Signed so that stepping off the edge is representable.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// The difference between two points.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vector {
+    pub dx: i32,
+    pub dy: i32,
+}
+
+impl Point {
+    pub const fn new(x: i32, y: i32) -> Self {
+        Point { x, y }
+    }
+
+    /// This point moved by `dx` columns and `dy` rows.
+    pub const fn offset(self, dx: i32, dy: i32) -> Self {
+        Point::new(self.x + dx, self.y + dy)
+    }
+
+    /// The neighbouring point in `direction`.
+    pub fn step(self, direction: Direction) -> Self {
+        self + direction.delta()
+    }
+
+    /// Number of orthogonal steps between the two points.
+    pub fn manhattan(self, other: Point) -> i32 {
+        (other - self).manhattan_len()
+    }
+}
+
+impl Vector {
+    pub const fn new(dx: i32, dy: i32) -> Self {
+        Vector { dx, dy }
+    }
+
+    pub fn manhattan_len(self) -> i32 {
+        self.dx.abs() + self.dy.abs()
+    }
+}
+
+impl Add<Vector> for Point {
+    type Output = Point;
+
+    fn add(self, v: Vector) -> Point {
+        self.offset(v.dx, v.dy)
+    }
+}
+
+impl AddAssign<Vector> for Point {
+    fn add_assign(&mut self, v: Vector) {
+        *self = *self + v;
+    }
+}
+
+impl Sub<Vector> for Point {
+    type Output = Point;
+
+    fn sub(self, v: Vector) -> Point {
+        self + -v
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, other: Point) -> Vector {
+        Vector::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, other: Vector) -> Vector {
+        Vector::new(self.dx + other.dx, self.dy + other.dy)
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        Vector::new(-self.dx, -self.dy)
+    }
+}
+
+impl Mul<i32> for Vector {
+    type Output = Vector;
+
+    fn mul(self, factor: i32) -> Vector {
+        Vector::new(self.dx * factor, self.dy * factor)
+    }
+}
+
+/// One of the four orthogonal directions.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Clockwise from north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn delta(self) -> Vector {
+        match self {
+            Direction::North => Vector::new(0, -1),
+            Direction::East => Vector::new(1, 0),
+            Direction::South => Vector::new(0, 1),
+            Direction::West => Vector::new(-1, 0),
+        }
+    }
+
+    /// A quarter turn clockwise.
+    pub fn turn_right(self) -> Direction {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    /// A quarter turn anticlockwise.
+    pub fn turn_left(self) -> Direction {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Direction {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+}
+
+/// One of the eight compass directions, diagonals included.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    /// Clockwise from north.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    /// Only the four diagonals, clockwise from north-east.
+    pub const DIAGONALS: [Direction8; 4] = [
+        Direction8::NorthEast,
+        Direction8::SouthEast,
+        Direction8::SouthWest,
+        Direction8::NorthWest,
+    ];
+
+    pub fn delta(self) -> Vector {
+        match self {
+            Direction8::North => Vector::new(0, -1),
+            Direction8::NorthEast => Vector::new(1, -1),
+            Direction8::East => Vector::new(1, 0),
+            Direction8::SouthEast => Vector::new(1, 1),
+            Direction8::South => Vector::new(0, 1),
+            Direction8::SouthWest => Vector::new(-1, 1),
+            Direction8::West => Vector::new(-1, 0),
+            Direction8::NorthWest => Vector::new(-1, -1),
+        }
+    }
+
+    /// Rotate clockwise by `steps` eighths of a turn; negative goes anticlockwise.
+    pub fn rotate(self, steps: i32) -> Direction8 {
+        Direction8::ALL[(self as i32 + steps).rem_euclid(8) as usize]
+    }
+
+    pub fn reverse(self) -> Direction8 {
+        self.rotate(4)
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Direction8 {
+        Direction8::ALL[direction as usize * 2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn north_is_up() {
+        let p = Point::new(3, 3);
+        assert_eq!(p.step(Direction::North), Point::new(3, 2));
+        assert_eq!(p.step(Direction::East), Point::new(4, 3));
+        assert_eq!(p.step(Direction::South), Point::new(3, 4));
+        assert_eq!(p.step(Direction::West), Point::new(2, 3));
+    }
+
+    #[test]
+    fn turning() {
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::West.turn_right(), Direction::North);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::East.reverse(), Direction::West);
+        for d in Direction::ALL {
+            assert_eq!(d.turn_left().turn_right(), d);
+            assert_eq!(d.reverse().reverse(), d);
+            assert_eq!(d.reverse().delta(), -d.delta());
+        }
+    }
+
+    #[test]
+    fn eight_way() {
+        assert_eq!(Direction8::North.rotate(1), Direction8::NorthEast);
+        assert_eq!(Direction8::North.rotate(-1), Direction8::NorthWest);
+        assert_eq!(Direction8::SouthWest.reverse(), Direction8::NorthEast);
+        for d in Direction8::ALL {
+            assert_eq!(d.reverse().delta(), -d.delta());
+        }
+        for d in Direction::ALL {
+            assert_eq!(Direction8::from(d).delta(), d.delta());
+        }
+    }
+
+    #[test]
+    fn vector_arithmetic() {
+        let a = Point::new(1, 2);
+        let b = Point::new(4, -2);
+        let v = b - a;
+        assert_eq!(v, Vector::new(3, -4));
+        assert_eq!(a + v, b);
+        assert_eq!(b - v, a);
+        assert_eq!(a + v * 2, Point::new(7, -6));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(b.manhattan(a), 7);
+    }
+}
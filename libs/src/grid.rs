@@ -0,0 +1,353 @@
+//! A rectangular grid of cells stored flat in row-major order.
+
+use crate::error::{self, ParseError};
+use crate::geometry::{Direction, Direction8, Point};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A `width` x `height` grid with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Parse one row per non-blank line, turning each character into a cell
+    /// with `cell`. A character `cell` rejects is reported as not `expected`.
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = Vec::new();
+        let (mut width, mut height) = (0, 0);
+
+        for (idx, line) in input.lines().enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+
+            let mut row_width = 0;
+            for (pos, ch) in line.chars().enumerate() {
+                let value = cell(ch).ok_or_else(|| error::bad_char(idx + 1, pos, ch, expected))?;
+                cells.push(value);
+                row_width += 1;
+            }
+            if height > 0 && row_width != width {
+                let expected = format!("a row of width {}", width);
+                let found = format!("width {}", row_width);
+                return Err(ParseError::new(idx + 1, 1, expected, found));
+            }
+            width = row_width;
+            height += 1;
+        }
+
+        if height == 0 {
+            return Err(ParseError::end_of_input(1, "a grid"));
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        p.x >= 0 && p.y >= 0 && (p.x as usize) < self.width && (p.y as usize) < self.height
+    }
+
+    fn offset_of(&self, p: Point) -> Option<usize> {
+        self.contains(p)
+            .then(|| p.y as usize * self.width + p.x as usize)
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.offset_of(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.offset_of(p).map(|i| &mut self.cells[i])
+    }
+
+    /// Every position, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| Point::new((i % width) as i32, (i / width) as i32))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The in-bounds points north, east, south and west of `p`.
+    pub fn neighbours4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .iter()
+            .map(move |&d| p + d.delta())
+            .filter(|&n| self.contains(n))
+    }
+
+    /// The in-bounds points around `p`, diagonals included, clockwise from north.
+    pub fn neighbours8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction8::ALL
+            .iter()
+            .map(move |&d| p + d.delta())
+            .filter(|&n| self.contains(n))
+    }
+
+    /// A grid of the same shape with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// The first position holding `value`, row by row.
+    pub fn find(&self, value: &T) -> Option<Point> {
+        self.iter().find(|(_, cell)| *cell == value).map(|(p, _)| p)
+    }
+
+    /// Every position holding `value`, row by row.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Point> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(p, _)| p)
+    }
+}
+
+impl Grid<char> {
+    /// Parse a grid of characters, each of which must be one of `allowed`.
+    pub fn parse_chars(input: &str, allowed: &str) -> Result<Self, ParseError> {
+        let expected = format!("one of '{}'", allowed);
+        Grid::parse(input, &expected, |ch| allowed.contains(ch).then_some(ch))
+    }
+
+    /// The position of the one `target`; it is an error for there to be none
+    /// or more than one.
+    pub fn find_one(&self, target: char) -> Result<Point, ParseError> {
+        let mut found = self.find_all(&target);
+        let first = found.next().ok_or_else(|| {
+            ParseError::end_of_input(self.height + 1, format!("a '{}' in the grid", target))
+        })?;
+        match found.next() {
+            Some(p) => {
+                let expected = format!("a single '{}'", target);
+                Err(error::bad_char(
+                    p.y as usize + 1,
+                    p.x as usize,
+                    target,
+                    expected,
+                ))
+            }
+            None => Ok(first),
+        }
+    }
+
+    /// Check that the outer ring is all `wall`, so nothing can walk off the grid.
+    pub fn check_walled(&self, wall: char) -> Result<(), ParseError> {
+        let (right, bottom) = (self.width as i32 - 1, self.height as i32 - 1);
+        for (p, &ch) in self.iter() {
+            let edge = p.x == 0 || p.y == 0 || p.x == right || p.y == bottom;
+            if edge && ch != wall {
+                let expected = format!("'{}' around the edge of the grid", wall);
+                return Err(error::bad_char(
+                    p.y as usize + 1,
+                    p.x as usize,
+                    ch,
+                    expected,
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        match self.get(p) {
+            Some(cell) => cell,
+            None => panic!("{:?} is outside a {}x{} grid", p, self.width, self.height),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(p) {
+            Some(cell) => cell,
+            None => panic!("{:?} is outside a {}x{} grid", p, width, height),
+        }
+    }
+}
+
+/// One line per row, with no separator between cells.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "#.#\n.S.\n##E\n";
+
+    #[test]
+    fn parses_row_major() {
+        let grid = Grid::parse_chars(SAMPLE, "#.SE").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid.get(Point::new(1, 1)), Some(&'S'));
+        assert_eq!(grid.get(Point::new(2, 2)), Some(&'E'));
+        assert_eq!(grid[Point::new(0, 2)], '#');
+    }
+
+    #[test]
+    fn skips_blank_lines() {
+        let grid = Grid::parse_chars("\n.#\n\n#.\n", ".#").unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+    }
+
+    #[test]
+    fn get_is_checked() {
+        let grid = Grid::parse_chars(SAMPLE, "#.SE").unwrap();
+        assert_eq!(grid.get(Point::new(-1, 0)), None);
+        assert_eq!(grid.get(Point::new(0, -1)), None);
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(0, 3)), None);
+    }
+
+    #[test]
+    fn parse_reports_bad_characters_and_ragged_rows() {
+        let error = Grid::parse_chars("..\n.x\n", ".").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.found, "'x'");
+
+        let error = Grid::parse_chars("..\n...\n", ".").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+
+        let error = Grid::parse_chars("\n\n", ".").unwrap_err();
+        assert_eq!(error.found, "end of input");
+    }
+
+    #[test]
+    fn parse_with_a_mapper() {
+        let grid = Grid::parse("09\n18\n", "a digit", |ch| ch.to_digit(10)).unwrap();
+        assert_eq!(grid[Point::new(1, 0)], 9);
+        assert_eq!(grid[Point::new(0, 1)], 1);
+    }
+
+    #[test]
+    fn neighbours_stay_in_bounds() {
+        let grid = Grid::new(3, 3, 0);
+
+        let corner: Vec<Point> = grid.neighbours4(Point::new(0, 0)).collect();
+        assert_eq!(corner, vec![Point::new(1, 0), Point::new(0, 1)]);
+
+        let centre: Vec<Point> = grid.neighbours4(Point::new(1, 1)).collect();
+        assert_eq!(
+            centre,
+            vec![
+                Point::new(1, 0),
+                Point::new(2, 1),
+                Point::new(1, 2),
+                Point::new(0, 1)
+            ]
+        );
+
+        assert_eq!(grid.neighbours8(Point::new(1, 1)).count(), 8);
+        assert_eq!(grid.neighbours8(Point::new(0, 0)).count(), 3);
+        assert_eq!(grid.neighbours8(Point::new(2, 1)).count(), 5);
+    }
+
+    #[test]
+    fn find_by_value() {
+        let grid = Grid::parse_chars("a.a\n.a.\n", "a.").unwrap();
+        assert_eq!(grid.find(&'a'), Some(Point::new(0, 0)));
+        assert_eq!(grid.find(&'z'), None);
+        assert_eq!(grid.find_all(&'a').count(), 3);
+    }
+
+    #[test]
+    fn find_one_rejects_missing_and_repeated() {
+        let grid = Grid::parse_chars(SAMPLE, "#.SE").unwrap();
+        assert_eq!(grid.find_one('S'), Ok(Point::new(1, 1)));
+        assert!(grid.find_one('.').is_err());
+
+        let error = grid.find_one('x').unwrap_err();
+        assert_eq!(error.found, "end of input");
+    }
+
+    #[test]
+    fn check_walled_finds_gaps() {
+        let walled = Grid::parse_chars("###\n#.#\n###\n", "#.").unwrap();
+        assert!(walled.check_walled('#').is_ok());
+
+        let open = Grid::parse_chars("###\n#..\n###\n", "#.").unwrap();
+        let error = open.check_walled('#').unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+    }
+
+    #[test]
+    fn indexing_and_mutation() {
+        let mut grid = Grid::new(2, 2, '.');
+        grid[Point::new(1, 0)] = '#';
+        *grid.get_mut(Point::new(0, 1)).unwrap() = '@';
+        assert!(grid.get_mut(Point::new(2, 0)).is_none());
+        assert_eq!(grid.to_string(), ".#\n@.\n");
+    }
+
+    #[test]
+    fn map_keeps_shape() {
+        let grid = Grid::parse_chars(SAMPLE, "#.SE").unwrap();
+        let walls = grid.map(|&ch| ch == '#');
+        assert_eq!((walls.width(), walls.height()), (3, 3));
+        assert!(walls[Point::new(0, 0)]);
+        assert!(!walls[Point::new(1, 1)]);
+    }
+
+    #[test]
+    fn display_round_trips() {
+        let grid = Grid::parse_chars(SAMPLE, "#.SE").unwrap();
+        assert_eq!(grid.to_string(), SAMPLE);
+    }
+}
@@ -0,0 +1,168 @@
+//! Locating puzzle inputs: an explicit path, `-` for stdin, or a named file
+//! under `inputs/dayNN/`.
+
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Input name used when none is given on the command line.
+pub const DEFAULT_INPUT: &str = "data";
+
+/// Where a puzzle input will be read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "<stdin>"),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    /// `inputs/dayNN/<name>.txt` does not exist; `available` lists the names that do.
+    NotFound {
+        day: u8,
+        name: String,
+        path: PathBuf,
+        available: Vec<String>,
+    },
+    /// No `inputs/` directory above the current directory or the workspace root.
+    NoInputsDir,
+    Io {
+        source: String,
+        error: io::Error,
+    },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::NotFound {
+                day,
+                name,
+                path,
+                available,
+            } => {
+                write!(
+                    f,
+                    "no input '{}' for day {}: {} does not exist",
+                    name,
+                    day,
+                    path.display()
+                )?;
+                if available.is_empty() {
+                    write!(f, " (no inputs for this day yet)")
+                } else {
+                    write!(f, " (available: {})", available.join(", "))
+                }
+            }
+            InputError::NoInputsDir => write!(f, "could not find an inputs/ directory"),
+            InputError::Io { source, error } => write!(f, "could not read {}: {}", source, error),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// Find the `inputs/` directory by walking up from the current directory,
+/// falling back to the one at the workspace root.
+pub fn inputs_dir() -> Option<PathBuf> {
+    if let Ok(cwd) = env::current_dir() {
+        for dir in cwd.ancestors() {
+            let candidate = dir.join("inputs");
+            if candidate.is_dir() {
+                return Some(candidate);
+            }
+        }
+    }
+
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).join("../inputs");
+    workspace.is_dir().then_some(workspace)
+}
+
+/// Folder holding the inputs of one day, e.g. `inputs/day07`.
+pub fn day_dir(inputs: &Path, day: u8) -> PathBuf {
+    inputs.join(format!("day{:02}", day))
+}
+
+/// Names of the inputs stored for `day`, sorted.
+pub fn available(inputs: &Path, day: u8) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(day_dir(inputs, day)) else {
+        return Vec::new();
+    };
+
+    let mut names: Vec<String> = entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.extension()? != "txt" {
+                return None;
+            }
+            Some(path.file_stem()?.to_str()?.to_string())
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+/// Work out where `spec` points for `day`.
+///
+/// `-` means stdin, anything that looks like a path (contains a separator or
+/// an extension, or already exists) is used as is, and everything else is an
+/// input name looked up as `inputs/dayNN/<name>.txt`.
+pub fn resolve(day: u8, spec: &str) -> Result<Source, InputError> {
+    if spec == "-" {
+        return Ok(Source::Stdin);
+    }
+
+    let as_path = Path::new(spec);
+    if spec.contains(['/', '\\']) || as_path.extension().is_some() || as_path.is_file() {
+        return Ok(Source::File(as_path.to_path_buf()));
+    }
+
+    let inputs = inputs_dir().ok_or(InputError::NoInputsDir)?;
+    let path = day_dir(&inputs, day).join(format!("{}.txt", spec));
+    if path.is_file() {
+        Ok(Source::File(path))
+    } else {
+        Err(InputError::NotFound {
+            day,
+            name: spec.to_string(),
+            available: available(&inputs, day),
+            path,
+        })
+    }
+}
+
+/// Read the whole input `source` points at.
+pub fn read(source: &Source) -> Result<String, InputError> {
+    match source {
+        Source::Stdin => {
+            let mut content = String::new();
+            io::stdin()
+                .read_to_string(&mut content)
+                .map_err(|error| InputError::Io {
+                    source: "stdin".to_string(),
+                    error,
+                })?;
+            Ok(content)
+        }
+        Source::File(path) => fs::read_to_string(path).map_err(|error| InputError::Io {
+            source: format!("'{}'", path.display()),
+            error,
+        }),
+    }
+}
+
+/// Resolve and read an input in one go.
+pub fn load(day: u8, spec: &str) -> Result<String, InputError> {
+    read(&resolve(day, spec)?)
+}
@@ -0,0 +1,35 @@
+//! Shared helpers used by every day's solver.
+
+pub mod error;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod log;
+pub mod metrics;
+pub mod record;
+pub mod search;
+pub mod viz;
+
+pub use error::ParseError;
+pub use geometry::{Direction, Direction8, Point, Vector};
+pub use grid::Grid;
+
+use std::fmt::Display;
+
+/// A named alternative implementation, run on an already parsed puzzle.
+pub type Variant<S> = (&'static str, fn(&S) -> String);
+
+/// One day's puzzle: parse the input once, then answer each part from it.
+pub trait Solution: Sized {
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self, ParseError>;
+    fn part1(&self) -> Self::Answer1;
+    fn part2(&self) -> Self::Answer2;
+
+    /// Competing implementations that `aoc bench` should time side by side.
+    fn variants() -> Vec<Variant<Self>> {
+        Vec::new()
+    }
+}
@@ -0,0 +1,80 @@
+//! A tiny logging facade for solver diagnostics.
+//!
+//! Messages go to stderr so that stdout only carries answers. The level is
+//! global and set once by the runner from `-q`/`-v`; solvers just call
+//! `info!`, `debug!` or `trace!` and check [`enabled`] before doing any
+//! expensive drawing.
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    /// Nothing but the answers.
+    Quiet,
+    /// Notable events, such as a solver giving up on an input.
+    Info,
+    /// Per-input summaries and intermediate results.
+    Debug,
+    /// Step-by-step traces and map redraws.
+    Trace,
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Info as u8);
+
+impl Level {
+    /// Level for a verbosity count: 0 is the default, each `-v` adds one and
+    /// each `-q` takes one away.
+    pub fn from_verbosity(verbosity: i32) -> Level {
+        match verbosity {
+            i32::MIN..=-1 => Level::Quiet,
+            0 => Level::Info,
+            1 => Level::Debug,
+            _ => Level::Trace,
+        }
+    }
+}
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn level() -> Level {
+    match LEVEL.load(Ordering::Relaxed) {
+        0 => Level::Quiet,
+        1 => Level::Info,
+        2 => Level::Debug,
+        _ => Level::Trace,
+    }
+}
+
+/// Whether messages at `level` are currently shown.
+pub fn enabled(level: Level) -> bool {
+    level != Level::Quiet && level <= self::level()
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Info) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Debug) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Trace) {
+            eprintln!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,38 @@
+//! Solver-specific numbers reported alongside an answer, such as how many
+//! optimal paths day 16 found.
+//!
+//! Collection is off unless the runner asks for it, so solvers can call
+//! [`record`] unconditionally; values are only serialized while a collection
+//! is active.
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::cell::RefCell;
+
+thread_local! {
+    static METRICS: RefCell<Option<Map<String, Value>>> = const { RefCell::new(None) };
+}
+
+/// Start collecting metrics on this thread, discarding anything left over.
+pub fn start() {
+    METRICS.with(|metrics| *metrics.borrow_mut() = Some(Map::new()));
+}
+
+/// Stop collecting and return everything recorded since [`start`].
+pub fn finish() -> Map<String, Value> {
+    METRICS.with(|metrics| metrics.borrow_mut().take().unwrap_or_default())
+}
+
+pub fn enabled() -> bool {
+    METRICS.with(|metrics| metrics.borrow().is_some())
+}
+
+/// Record `value` under `name`, replacing any earlier value with that name.
+pub fn record<T: Serialize>(name: &str, value: T) {
+    METRICS.with(|metrics| {
+        if let Some(map) = metrics.borrow_mut().as_mut() {
+            let value = serde_json::to_value(value).unwrap_or(Value::Null);
+            map.insert(name.to_string(), value);
+        }
+    });
+}
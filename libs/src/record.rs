@@ -0,0 +1,543 @@
+//! Save visualization frames as animated images.
+//!
+//! A [`Recorder`] collects the frames a solver shows (see [`crate::viz`]) and
+//! writes them out once the run is over, as an animated GIF, an animated PNG
+//! or one numbered PNG or PPM file per frame. Nothing here needs a display.
+//!
+//! Each cell becomes a `cell_size` square of a single colour. A cell with a
+//! colour uses that colour's entry in the [`Palette`]; an uncoloured `.` or
+//! space is background and any other glyph is foreground. Captions are not
+//! drawn.
+
+use crate::grid::Grid;
+use crate::viz::{Cell, Color, Frame};
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+pub type Rgb = [u8; 3];
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Format {
+    Gif,
+    Apng,
+    /// A directory of `frame_00001.png`, `frame_00002.png`, ...
+    PngSequence,
+    /// A directory of `frame_00001.ppm`, ... in binary PPM (P6).
+    PpmSequence,
+}
+
+impl Format {
+    /// `gif`, `apng`, `png` (a sequence) or `ppm` (a sequence).
+    pub fn from_name(name: &str) -> Option<Format> {
+        match name {
+            "gif" => Some(Format::Gif),
+            "apng" => Some(Format::Apng),
+            "png" => Some(Format::PngSequence),
+            "ppm" => Some(Format::PpmSequence),
+            _ => None,
+        }
+    }
+
+    /// The animated format a file name asks for: `.gif`, or `.png`/`.apng`.
+    pub fn from_path(path: &Path) -> Option<Format> {
+        match path.extension()?.to_str()? {
+            "gif" => Some(Format::Gif),
+            "png" | "apng" => Some(Format::Apng),
+            _ => None,
+        }
+    }
+}
+
+/// The colours frames are drawn in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    pub background: Rgb,
+    pub foreground: Rgb,
+    /// Indexed like [`COLOR_NAMES`].
+    pub colors: [Rgb; 16],
+}
+
+/// The named terminal colours, as written in palette files.
+pub const COLOR_NAMES: [&str; 16] = [
+    "black",
+    "dark_red",
+    "dark_green",
+    "dark_yellow",
+    "dark_blue",
+    "dark_magenta",
+    "dark_cyan",
+    "grey",
+    "dark_grey",
+    "red",
+    "green",
+    "yellow",
+    "blue",
+    "magenta",
+    "cyan",
+    "white",
+];
+
+impl Palette {
+    /// Light on black, like most terminals.
+    pub fn dark() -> Palette {
+        Palette {
+            background: [0x10, 0x10, 0x18],
+            foreground: [0xd0, 0xd0, 0xd0],
+            colors: [
+                [0x00, 0x00, 0x00],
+                [0x80, 0x00, 0x00],
+                [0x00, 0x80, 0x00],
+                [0x80, 0x80, 0x00],
+                [0x00, 0x00, 0x80],
+                [0x80, 0x00, 0x80],
+                [0x00, 0x80, 0x80],
+                [0xc0, 0xc0, 0xc0],
+                [0x80, 0x80, 0x80],
+                [0xff, 0x40, 0x40],
+                [0x40, 0xff, 0x40],
+                [0xff, 0xff, 0x40],
+                [0x50, 0x70, 0xff],
+                [0xff, 0x40, 0xff],
+                [0x40, 0xff, 0xff],
+                [0xff, 0xff, 0xff],
+            ],
+        }
+    }
+
+    /// Dark on white, for slides and documents. White cells become black.
+    pub fn light() -> Palette {
+        let mut palette = Palette::dark();
+        palette.background = [0xff, 0xff, 0xff];
+        palette.foreground = [0x30, 0x30, 0x30];
+        palette.colors[11] = [0xd0, 0xa0, 0x00];
+        palette.colors[15] = [0x00, 0x00, 0x00];
+        palette
+    }
+
+    /// A built-in palette by name: `dark` or `light`.
+    pub fn named(name: &str) -> Option<Palette> {
+        match name {
+            "dark" => Some(Palette::dark()),
+            "light" => Some(Palette::light()),
+            _ => None,
+        }
+    }
+
+    /// Start from the dark palette and apply `name = #rrggbb` lines, where
+    /// the name is `background`, `foreground` or one of [`COLOR_NAMES`].
+    /// `#` at the start of a line begins a comment.
+    pub fn parse(text: &str) -> Result<Palette, String> {
+        let mut palette = Palette::dark();
+        for (idx, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let invalid = || {
+                format!(
+                    "line {}: expected 'name = #rrggbb', found '{}'",
+                    idx + 1,
+                    line
+                )
+            };
+            let (name, value) = line.split_once('=').ok_or_else(invalid)?;
+            let rgb = parse_rgb(value.trim()).ok_or_else(invalid)?;
+            match name.trim() {
+                "background" => palette.background = rgb,
+                "foreground" => palette.foreground = rgb,
+                other => match COLOR_NAMES.iter().position(|&known| known == other) {
+                    Some(index) => palette.colors[index] = rgb,
+                    None => return Err(format!("line {}: unknown colour '{}'", idx + 1, other)),
+                },
+            }
+        }
+        Ok(palette)
+    }
+
+    /// The colour a cell is drawn in.
+    pub fn rgb(&self, cell: &Cell) -> Rgb {
+        match cell.color {
+            Some(color) => self.color(color),
+            None if cell.glyph == '.' || cell.glyph == ' ' => self.background,
+            None => self.foreground,
+        }
+    }
+
+    fn color(&self, color: Color) -> Rgb {
+        let index = match color {
+            Color::Rgb { r, g, b } => return [r, g, b],
+            Color::Reset => return self.foreground,
+            Color::AnsiValue(value) if value < 16 => value as usize,
+            Color::AnsiValue(value) => return ansi_rgb(value),
+            Color::Black => 0,
+            Color::DarkRed => 1,
+            Color::DarkGreen => 2,
+            Color::DarkYellow => 3,
+            Color::DarkBlue => 4,
+            Color::DarkMagenta => 5,
+            Color::DarkCyan => 6,
+            Color::Grey => 7,
+            Color::DarkGrey => 8,
+            Color::Red => 9,
+            Color::Green => 10,
+            Color::Yellow => 11,
+            Color::Blue => 12,
+            Color::Magenta => 13,
+            Color::Cyan => 14,
+            Color::White => 15,
+        };
+        self.colors[index]
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Palette {
+        Palette::dark()
+    }
+}
+
+fn parse_rgb(text: &str) -> Option<Rgb> {
+    let hex = text.strip_prefix('#')?;
+    if hex.len() != 6 || !hex.is_ascii() {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    Some([channel(0)?, channel(2)?, channel(4)?])
+}
+
+/// The xterm 256-colour cube and grey ramp, for ANSI values 16 and up.
+fn ansi_rgb(value: u8) -> Rgb {
+    if value >= 232 {
+        let level = 8 + (value - 232) * 10;
+        return [level; 3];
+    }
+    let cube = value - 16;
+    let level = |n: u8| if n == 0 { 0 } else { 55 + n * 40 };
+    [level(cube / 36), level(cube / 6 % 6), level(cube % 6)]
+}
+
+#[derive(Debug, Clone)]
+pub struct Options {
+    pub format: Format,
+    /// The output file, or the directory for the sequence formats.
+    pub path: PathBuf,
+    /// Width and height in pixels of one grid cell.
+    pub cell_size: u32,
+    /// How long each frame stays up in the animated formats.
+    pub delay: Duration,
+    pub palette: Palette,
+}
+
+/// Frames waiting to be written, each stored as one colour index per cell.
+pub struct Recorder {
+    options: Options,
+    colors: Vec<Rgb>,
+    index: HashMap<Rgb, u8>,
+    size: Option<(usize, usize)>,
+    frames: Vec<Vec<u8>>,
+}
+
+/// GIF and indexed PNG both allow at most this many colours.
+const MAX_COLORS: usize = 256;
+
+impl Recorder {
+    pub fn new(options: Options) -> Recorder {
+        let mut recorder = Recorder {
+            options,
+            colors: Vec::new(),
+            index: HashMap::new(),
+            size: None,
+            frames: Vec::new(),
+        };
+        // Index 0 is the background, which also pads frames of another size
+        let background = recorder.options.palette.background;
+        recorder.color_index(background);
+        recorder
+    }
+
+    pub fn frame_count(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn options(&self) -> &Options {
+        &self.options
+    }
+
+    /// Add a frame. Every frame takes the size of the first: larger ones are
+    /// cropped and smaller ones padded with background.
+    pub fn push(&mut self, frame: &Frame) {
+        let cells: &Grid<Cell> = frame.cells();
+        let (width, height) = *self.size.get_or_insert((cells.width(), cells.height()));
+
+        let mut indices = vec![0; width * height];
+        for (p, cell) in cells.iter() {
+            let (x, y) = (p.x as usize, p.y as usize);
+            if x < width && y < height {
+                let rgb = self.options.palette.rgb(cell);
+                indices[y * width + x] = self.color_index(rgb);
+            }
+        }
+        self.frames.push(indices);
+    }
+
+    /// The palette index of `rgb`, adding it if there is room. Past 256
+    /// colours the closest existing one is used.
+    fn color_index(&mut self, rgb: Rgb) -> u8 {
+        if let Some(&index) = self.index.get(&rgb) {
+            return index;
+        }
+        if self.colors.len() < MAX_COLORS {
+            let index = self.colors.len() as u8;
+            self.colors.push(rgb);
+            self.index.insert(rgb, index);
+            return index;
+        }
+        let distance = |c: &Rgb| -> u32 {
+            (0..3)
+                .map(|i| (c[i] as i32 - rgb[i] as i32).unsigned_abs().pow(2))
+                .sum()
+        };
+        let closest = (0..self.colors.len())
+            .min_by_key(|&i| distance(&self.colors[i]))
+            .unwrap_or(0);
+        closest as u8
+    }
+
+    /// Image size in pixels.
+    fn pixel_size(&self) -> (u32, u32) {
+        let (width, height) = self.size.unwrap_or((0, 0));
+        let cell = self.options.cell_size.max(1);
+        (width as u32 * cell, height as u32 * cell)
+    }
+
+    /// One palette index per pixel, row by row, for the frame at `n`.
+    fn pixels(&self, n: usize) -> Vec<u8> {
+        let (width, _) = self.size.unwrap_or((0, 0));
+        let cell = self.options.cell_size.max(1) as usize;
+        let mut pixels = Vec::with_capacity(self.frames[n].len() * cell * cell);
+        for row in self.frames[n].chunks(width.max(1)) {
+            let line: Vec<u8> = row
+                .iter()
+                .flat_map(|&index| std::iter::repeat_n(index, cell))
+                .collect();
+            for _ in 0..cell {
+                pixels.extend_from_slice(&line);
+            }
+        }
+        pixels
+    }
+
+    fn flat_palette(&self) -> Vec<u8> {
+        self.colors.iter().flatten().copied().collect()
+    }
+
+    /// Write everything recorded. Returns what was written, for the user.
+    pub fn save(&self) -> io::Result<String> {
+        if self.frames.is_empty() {
+            return Err(io::Error::other("no frames were recorded"));
+        }
+        let path = &self.options.path;
+        match self.options.format {
+            Format::Gif => self.write_gif(path)?,
+            Format::Apng => self.write_apng(path)?,
+            Format::PngSequence | Format::PpmSequence => self.write_sequence(path)?,
+        }
+        let (width, height) = self.pixel_size();
+        Ok(format!(
+            "{} frames of {}x{} pixels to {}",
+            self.frames.len(),
+            width,
+            height,
+            path.display()
+        ))
+    }
+
+    fn write_gif(&self, path: &Path) -> io::Result<()> {
+        let (width, height) = self.pixel_size();
+        let (width, height) = match (u16::try_from(width), u16::try_from(height)) {
+            (Ok(width), Ok(height)) => (width, height),
+            _ => return Err(io::Error::other("frames are too large for a GIF")),
+        };
+        let file = BufWriter::new(File::create(path)?);
+        let mut encoder = gif::Encoder::new(file, width, height, &self.flat_palette())
+            .map_err(io::Error::other)?;
+        encoder
+            .set_repeat(gif::Repeat::Infinite)
+            .map_err(io::Error::other)?;
+
+        // GIF delays are in hundredths of a second
+        let delay = (self.options.delay.as_millis() / 10).clamp(1, u16::MAX as u128) as u16;
+        for n in 0..self.frames.len() {
+            let mut frame = gif::Frame::from_indexed_pixels(width, height, self.pixels(n), None);
+            frame.delay = delay;
+            encoder.write_frame(&frame).map_err(io::Error::other)?;
+        }
+        Ok(())
+    }
+
+    fn png_encoder<W: Write>(&self, out: W) -> png::Encoder<'_, W> {
+        let (width, height) = self.pixel_size();
+        let mut encoder = png::Encoder::new(out, width, height);
+        encoder.set_color(png::ColorType::Indexed);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.set_palette(self.flat_palette());
+        encoder
+    }
+
+    fn write_apng(&self, path: &Path) -> io::Result<()> {
+        let mut encoder = self.png_encoder(BufWriter::new(File::create(path)?));
+        encoder
+            .set_animated(self.frames.len() as u32, 0)
+            .map_err(io::Error::other)?;
+        let delay = self.options.delay.as_millis().clamp(1, u16::MAX as u128) as u16;
+        encoder
+            .set_frame_delay(delay, 1000)
+            .map_err(io::Error::other)?;
+
+        let mut writer = encoder.write_header().map_err(io::Error::other)?;
+        for n in 0..self.frames.len() {
+            writer
+                .write_image_data(&self.pixels(n))
+                .map_err(io::Error::other)?;
+        }
+        writer.finish().map_err(io::Error::other)
+    }
+
+    fn write_sequence(&self, dir: &Path) -> io::Result<()> {
+        fs::create_dir_all(dir)?;
+        let (width, height) = self.pixel_size();
+        for n in 0..self.frames.len() {
+            let pixels = self.pixels(n);
+            match self.options.format {
+                Format::PpmSequence => {
+                    let path = dir.join(format!("frame_{:05}.ppm", n + 1));
+                    let mut out = BufWriter::new(File::create(path)?);
+                    write!(out, "P6\n{} {}\n255\n", width, height)?;
+                    for &index in &pixels {
+                        out.write_all(&self.colors[index as usize])?;
+                    }
+                    out.flush()?;
+                }
+                _ => {
+                    let path = dir.join(format!("frame_{:05}.png", n + 1));
+                    let encoder = self.png_encoder(BufWriter::new(File::create(path)?));
+                    let mut writer = encoder.write_header().map_err(io::Error::other)?;
+                    writer.write_image_data(&pixels).map_err(io::Error::other)?;
+                    writer.finish().map_err(io::Error::other)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::Point;
+
+    fn options(format: Format, path: PathBuf) -> Options {
+        Options {
+            format,
+            path,
+            cell_size: 2,
+            delay: Duration::from_millis(100),
+            palette: Palette::dark(),
+        }
+    }
+
+    fn frames() -> Vec<Frame> {
+        let grid = Grid::parse_chars("#.\n.#\n", "#.").unwrap();
+        let first = Frame::chars(&grid);
+        let second = first.clone().mark(&[Point::new(1, 0)], 'o', Color::Red);
+        vec![first, second]
+    }
+
+    fn scratch(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("libs-record-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn cells_take_palette_colours() {
+        let palette = Palette::dark();
+        assert_eq!(palette.rgb(&Cell::from('.')), palette.background);
+        assert_eq!(palette.rgb(&Cell::from('#')), palette.foreground);
+        assert_eq!(
+            palette.rgb(&Cell::from(('o', Color::Red))),
+            palette.colors[9]
+        );
+        let rgb = Color::Rgb { r: 1, g: 2, b: 3 };
+        assert_eq!(palette.rgb(&Cell::from(('o', rgb))), [1, 2, 3]);
+    }
+
+    #[test]
+    fn palette_files_override_the_defaults() {
+        let palette = Palette::parse("# mine\nbackground = #ffffff\nred = #010203\n").unwrap();
+        assert_eq!(palette.background, [0xff, 0xff, 0xff]);
+        assert_eq!(palette.colors[9], [1, 2, 3]);
+        assert_eq!(palette.foreground, Palette::dark().foreground);
+
+        assert!(Palette::parse("purple = #000000").is_err());
+        assert!(Palette::parse("red = 000000").is_err());
+    }
+
+    #[test]
+    fn frames_are_scaled_by_the_cell_size() {
+        let mut recorder = Recorder::new(options(Format::Gif, PathBuf::new()));
+        for frame in frames() {
+            recorder.push(&frame);
+        }
+        assert_eq!(recorder.frame_count(), 2);
+        assert_eq!(recorder.pixel_size(), (4, 4));
+
+        // Background, foreground, then red
+        assert_eq!(
+            recorder.pixels(0),
+            [1, 1, 0, 0, 1, 1, 0, 0, 0, 0, 1, 1, 0, 0, 1, 1]
+        );
+        assert_eq!(recorder.pixels(1)[2..4], [2, 2]);
+    }
+
+    #[test]
+    fn writes_every_format() {
+        let dir = scratch("formats");
+        let cases = [
+            (Format::Gif, dir.join("out.gif")),
+            (Format::Apng, dir.join("out.png")),
+            (Format::PngSequence, dir.join("png")),
+            (Format::PpmSequence, dir.join("ppm")),
+        ];
+        for (format, path) in cases {
+            let mut recorder = Recorder::new(options(format, path.clone()));
+            for frame in frames() {
+                recorder.push(&frame);
+            }
+            recorder.save().unwrap();
+        }
+
+        assert!(fs::read(dir.join("out.gif"))
+            .unwrap()
+            .starts_with(b"GIF89a"));
+        let apng = fs::read(dir.join("out.png")).unwrap();
+        assert!(apng.starts_with(b"\x89PNG"));
+        assert!(apng.windows(4).any(|chunk| chunk == b"acTL"));
+        assert!(dir.join("png/frame_00002.png").exists());
+        let ppm = fs::read(dir.join("ppm/frame_00001.ppm")).unwrap();
+        assert!(ppm.starts_with(b"P6\n4 4\n255\n"));
+        assert_eq!(ppm.len(), "P6\n4 4\n255\n".len() + 4 * 4 * 3);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn nothing_recorded_is_an_error() {
+        let recorder = Recorder::new(options(Format::Gif, PathBuf::from("unused.gif")));
+        assert!(recorder.save().is_err());
+    }
+}
@@ -0,0 +1,382 @@
+//! Shortest paths over any state type: breadth-first search, Dijkstra and A*.
+//!
+//! The graph is never built up front. Each search takes a starting state and
+//! a closure listing the neighbours of a state, and an `is_goal` test that
+//! stops the search once every goal state of the lowest cost has been found.
+//! Pass `|_| false` to explore everything reachable.
+//!
+//! Besides the distances, a search remembers every predecessor that reaches a
+//! state at its best cost, so all the shortest paths can be recovered, not
+//! just one. Step costs must be positive for that to be well defined.
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// What a search found: the best cost of every state it settled and how it got there.
+#[derive(Debug, Clone)]
+pub struct Search<S, C> {
+    start: S,
+    distances: HashMap<S, C>,
+    predecessors: HashMap<S, Vec<S>>,
+    goals: Vec<S>,
+}
+
+/// A heap entry, ordered so that `BinaryHeap` pops the lowest priority first.
+struct Entry<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> PartialEq for Entry<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for Entry<S, C> {}
+
+impl<S, C: Ord> Ord for Entry<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<S, C: Ord> PartialOrd for Entry<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Search<S, C> {
+    fn new(start: S, zero: C) -> Self {
+        Search {
+            distances: HashMap::from([(start.clone(), zero)]),
+            predecessors: HashMap::new(),
+            goals: Vec::new(),
+            start,
+        }
+    }
+
+    /// Record that `next` can be reached from `from` at `cost`. Returns true
+    /// when that is a new best, i.e. `next` needs (re)visiting.
+    fn relax(&mut self, from: &S, next: S, cost: C) -> bool
+    where
+        C: Ord,
+    {
+        match self.distances.get(&next) {
+            Some(&best) if cost > best => false,
+            Some(&best) if cost == best => {
+                self.predecessors
+                    .entry(next)
+                    .or_default()
+                    .push(from.clone());
+                false
+            }
+            _ => {
+                self.distances.insert(next.clone(), cost);
+                self.predecessors.insert(next, vec![from.clone()]);
+                true
+            }
+        }
+    }
+
+    pub fn start(&self) -> &S {
+        &self.start
+    }
+
+    /// The best cost of reaching `state`, if it was reached.
+    pub fn distance(&self, state: &S) -> Option<C> {
+        self.distances.get(state).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<S, C> {
+        &self.distances
+    }
+
+    pub fn into_distances(self) -> HashMap<S, C> {
+        self.distances
+    }
+
+    /// Every state that reaches `state` at its best cost, in the order found.
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map_or(&[], Vec::as_slice)
+    }
+
+    /// The goal states reached at the lowest cost, in the order they were settled.
+    pub fn goals(&self) -> &[S] {
+        &self.goals
+    }
+
+    /// The cost of the cheapest goal, if any goal was reached.
+    pub fn goal_cost(&self) -> Option<C> {
+        self.goals.first().and_then(|goal| self.distance(goal))
+    }
+
+    /// One shortest path from the start to `to`, both ends included.
+    pub fn path(&self, to: &S) -> Option<Vec<S>> {
+        self.distances.get(to)?;
+        let mut path = vec![to.clone()];
+        let mut current = to;
+        while let Some(previous) = self.predecessors(current).first() {
+            path.push(previous.clone());
+            current = previous;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every state that lies on some shortest path to one of `targets`.
+    pub fn on_shortest_paths<'a>(&'a self, targets: impl IntoIterator<Item = &'a S>) -> HashSet<S> {
+        let mut seen = HashSet::new();
+        let mut stack: Vec<&S> = targets
+            .into_iter()
+            .filter(|target| self.distances.contains_key(*target))
+            .collect();
+        while let Some(state) = stack.pop() {
+            if seen.insert(state.clone()) {
+                stack.extend(self.predecessors(state));
+            }
+        }
+        seen
+    }
+
+    /// How many distinct shortest paths lead from the start to one of `targets`.
+    pub fn count_paths<'a>(&'a self, targets: impl IntoIterator<Item = &'a S>) -> u64
+    where
+        C: Ord,
+    {
+        let targets: Vec<&S> = targets.into_iter().collect();
+        let mut states: Vec<S> = self
+            .on_shortest_paths(targets.iter().copied())
+            .into_iter()
+            .collect();
+        // Predecessors are strictly cheaper, so this visits them first
+        states.sort_by_key(|state| self.distances[state]);
+
+        let mut counts: HashMap<&S, u64> = HashMap::new();
+        for state in &states {
+            let count = match self.predecessors(state) {
+                [] => 1,
+                previous => previous
+                    .iter()
+                    .map(|p| counts.get(p).copied().unwrap_or(0))
+                    .sum(),
+            };
+            counts.insert(state, count);
+        }
+        targets
+            .iter()
+            .map(|target| counts.get(target).copied().unwrap_or(0))
+            .sum()
+    }
+
+    /// Every shortest path from the start to `to`. There can be exponentially
+    /// many; use `on_shortest_paths` or `count_paths` when that is enough.
+    pub fn all_paths(&self, to: &S) -> Vec<Vec<S>> {
+        let mut paths = Vec::new();
+        if !self.distances.contains_key(to) {
+            return paths;
+        }
+
+        // Walk backwards from `to`, branching at every equal-cost predecessor
+        let mut stack = vec![vec![to.clone()]];
+        while let Some(partial) = stack.pop() {
+            let last = partial.last().expect("partial paths are never empty");
+            match self.predecessors(last) {
+                [] => {
+                    let mut path = partial;
+                    path.reverse();
+                    paths.push(path);
+                }
+                previous => {
+                    for p in previous.iter().rev() {
+                        let mut longer = partial.clone();
+                        longer.push(p.clone());
+                        stack.push(longer);
+                    }
+                }
+            }
+        }
+        paths
+    }
+}
+
+/// Breadth-first search, where every step costs one.
+pub fn bfs<S, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new(start.clone(), 0);
+    let mut queue = VecDeque::from([start]);
+    let mut goal_cost = None;
+
+    while let Some(state) = queue.pop_front() {
+        let cost = search.distances[&state];
+        if goal_cost.is_some_and(|goal| cost > goal) {
+            break;
+        }
+        if is_goal(&state) {
+            goal_cost = Some(cost);
+            search.goals.push(state);
+            continue;
+        }
+        for next in neighbours(&state) {
+            if search.relax(&state, next.clone(), cost + 1) {
+                queue.push_back(next);
+            }
+        }
+    }
+    search
+}
+
+/// Dijkstra's algorithm: `neighbours` lists each next state with the cost of the step.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    neighbours: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, neighbours, |_| C::default(), is_goal)
+}
+
+/// A*: like `dijkstra`, but explores states in order of cost so far plus
+/// `heuristic`. The heuristic must never overestimate the remaining cost and
+/// must be zero at the goals, or the answer may not be the cheapest.
+pub fn astar<S, C, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let zero = C::default();
+    let mut search = Search::new(start.clone(), zero);
+    let mut heap = BinaryHeap::from([Entry {
+        priority: heuristic(&start),
+        cost: zero,
+        state: start,
+    }]);
+    let mut goal_cost = None;
+
+    while let Some(Entry {
+        priority,
+        cost,
+        state,
+    }) = heap.pop()
+    {
+        if goal_cost.is_some_and(|goal| priority > goal) {
+            break;
+        }
+        // Skip entries left behind after a cheaper way in was found
+        if cost > search.distances[&state] {
+            continue;
+        }
+        if is_goal(&state) {
+            goal_cost = Some(cost);
+            search.goals.push(state);
+            continue;
+        }
+        for (next, step) in neighbours(&state) {
+            let next_cost = cost + step;
+            if search.relax(&state, next.clone(), next_cost) {
+                heap.push(Entry {
+                    priority: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    state: next,
+                });
+            }
+        }
+    }
+    search
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Grid, Point};
+
+    /// Two equally short ways round the wall in the middle.
+    const RING: &str = "...\n.#.\n...\n";
+
+    fn open(grid: &Grid<char>) -> impl FnMut(&Point) -> Vec<Point> + '_ {
+        move |&p| grid.neighbours4(p).filter(|&n| grid[n] != '#').collect()
+    }
+
+    #[test]
+    fn bfs_finds_every_shortest_path() {
+        let grid = Grid::parse_chars(RING, ".#").unwrap();
+        let (start, end) = (Point::new(0, 0), Point::new(2, 2));
+        let search = bfs(start, open(&grid), |&p| p == end);
+
+        assert_eq!(search.goals(), &[end]);
+        assert_eq!(search.goal_cost(), Some(4));
+        assert_eq!(search.predecessors(&end).len(), 2);
+        assert_eq!(search.count_paths([&end]), 2);
+        assert_eq!(search.all_paths(&end).len(), 2);
+        assert_eq!(search.on_shortest_paths([&end]).len(), 8);
+
+        let path = search.path(&end).unwrap();
+        assert_eq!(path.len(), 5);
+        assert_eq!((path[0], path[4]), (start, end));
+    }
+
+    #[test]
+    fn without_a_goal_everything_reachable_is_settled() {
+        let grid = Grid::parse_chars("..#.\n", ".#").unwrap();
+        let search = bfs(Point::new(0, 0), open(&grid), |_| false);
+        assert_eq!(search.distances().len(), 2);
+        assert_eq!(search.distance(&Point::new(1, 0)), Some(1));
+        assert_eq!(search.distance(&Point::new(3, 0)), None);
+        assert!(search.goals().is_empty());
+        assert_eq!(search.path(&Point::new(3, 0)), None);
+    }
+
+    #[test]
+    fn dijkstra_prefers_cheap_edges_over_few() {
+        // 0 -> 3 directly costs 10, going through 1 and 2 costs 3
+        let edges = |&n: &u32| match n {
+            0 => vec![(1, 1), (3, 10)],
+            1 => vec![(2, 1)],
+            2 => vec![(3, 1)],
+            _ => vec![],
+        };
+        let search = dijkstra(0, edges, |&n| n == 3);
+        assert_eq!(search.goal_cost(), Some(3));
+        assert_eq!(search.path(&3), Some(vec![0, 1, 2, 3]));
+    }
+
+    #[test]
+    fn astar_agrees_with_dijkstra() {
+        let grid = Grid::parse_chars(".....\n.###.\n...#.\n.#...\n", ".#").unwrap();
+        let end = Point::new(4, 3);
+        let steps = |&p: &Point| -> Vec<(Point, i32)> {
+            grid.neighbours4(p)
+                .filter(|&n| grid[n] != '#')
+                .map(|n| (n, 1))
+                .collect()
+        };
+
+        let plain = dijkstra(Point::new(0, 0), steps, |&p| p == end);
+        let guided = astar(Point::new(0, 0), steps, |p| p.manhattan(end), |&p| p == end);
+        assert_eq!(plain.goal_cost(), Some(7));
+        assert_eq!(guided.goal_cost(), plain.goal_cost());
+        assert_eq!(guided.count_paths([&end]), plain.count_paths([&end]));
+        assert!(guided.distances().len() <= plain.distances().len());
+    }
+}
@@ -0,0 +1,442 @@
+//! Animate a solver on the terminal.
+//!
+//! A solver builds a [`Frame`] from one of its grids, adds overlays (coloured
+//! cell sets, highlighted paths, a caption) and hands it to [`show`]. How the
+//! frame is drawn is up to the runner, which picks a [`Mode`] once with
+//! [`configure`]:
+//!
+//! - [`Mode::Terminal`] redraws in place on an alternate screen, paced to a
+//!   frame rate. Space pauses, `n` steps one frame while paused, `+` and `-`
+//!   change the speed and `q` stops the animation (the solver carries on).
+//! - [`Mode::Text`] prints every frame as plain text to stderr, for logs and
+//!   terminals that can't do better.
+//! - [`Mode::Off`] draws nothing; solvers check [`enabled`] before building
+//!   frames so that this costs nothing.
+//!
+//! Independently of the mode, [`record`] keeps every frame in a
+//! [`Recorder`] to be saved as an animation at the end.
+
+use crate::geometry::Point;
+use crate::grid::Grid;
+use crate::record::Recorder;
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::style::{Print, ResetColor, SetForegroundColor};
+use crossterm::{cursor, terminal, QueueableCommand};
+use std::io::{self, stderr, Write};
+use std::panic;
+use std::sync::{Mutex, Once};
+use std::time::{Duration, Instant};
+
+pub use crossterm::style::Color;
+
+/// Frame rate of the terminal view when none is asked for.
+pub const DEFAULT_FPS: u32 = 30;
+
+/// Distinct colours for numbered things, such as regions or robots.
+const PALETTE: [Color; 12] = [
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::DarkRed,
+    Color::DarkGreen,
+    Color::DarkYellow,
+    Color::DarkBlue,
+    Color::DarkMagenta,
+    Color::DarkCyan,
+];
+
+/// The `index`th palette colour, wrapping around.
+pub fn palette(index: usize) -> Color {
+    PALETTE[index % PALETTE.len()]
+}
+
+/// One character on screen and its colour (the terminal's own if `None`).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Cell {
+    pub glyph: char,
+    pub color: Option<Color>,
+}
+
+impl From<char> for Cell {
+    fn from(glyph: char) -> Cell {
+        Cell { glyph, color: None }
+    }
+}
+
+impl From<(char, Color)> for Cell {
+    fn from((glyph, color): (char, Color)) -> Cell {
+        Cell {
+            glyph,
+            color: Some(color),
+        }
+    }
+}
+
+/// A picture of a grid, ready to show.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    cells: Grid<Cell>,
+    caption: String,
+}
+
+impl Frame {
+    /// Draw every cell of `grid` with `draw`, which gives a glyph and
+    /// optionally a colour.
+    pub fn new<T, C: Into<Cell>>(grid: &Grid<T>, mut draw: impl FnMut(&T) -> C) -> Frame {
+        Frame {
+            cells: grid.map(|value| draw(value).into()),
+            caption: String::new(),
+        }
+    }
+
+    /// A grid of characters drawn as they are.
+    pub fn chars(grid: &Grid<char>) -> Frame {
+        Frame::new(grid, |&ch| ch)
+    }
+
+    /// Text shown above the grid.
+    pub fn caption(mut self, caption: impl Into<String>) -> Frame {
+        self.caption = caption.into();
+        self
+    }
+
+    /// Colour `points`, keeping their glyphs. Points off the grid are ignored.
+    pub fn color<'a>(mut self, points: impl IntoIterator<Item = &'a Point>, color: Color) -> Frame {
+        for &p in points {
+            if let Some(cell) = self.cells.get_mut(p) {
+                cell.color = Some(color);
+            }
+        }
+        self
+    }
+
+    /// Draw `glyph` in `color` over `points`, e.g. to highlight a path.
+    pub fn mark<'a>(
+        mut self,
+        points: impl IntoIterator<Item = &'a Point>,
+        glyph: char,
+        color: Color,
+    ) -> Frame {
+        for &p in points {
+            self.set(p, (glyph, color));
+        }
+        self
+    }
+
+    /// Replace a single cell, if it is on the grid.
+    pub fn set(&mut self, p: Point, cell: impl Into<Cell>) {
+        if let Some(slot) = self.cells.get_mut(p) {
+            *slot = cell.into();
+        }
+    }
+
+    pub fn cells(&self) -> &Grid<Cell> {
+        &self.cells
+    }
+
+    pub fn caption_text(&self) -> &str {
+        &self.caption
+    }
+
+    /// The caption, if any, then the glyphs one row per line, without colours.
+    pub fn to_plain(&self) -> String {
+        let mut text = String::new();
+        if !self.caption.is_empty() {
+            text.push_str(&self.caption);
+            text.push('\n');
+        }
+        for row in self.cells.rows() {
+            text.extend(row.iter().map(|cell| cell.glyph));
+            text.push('\n');
+        }
+        text
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Mode {
+    Off,
+    Text,
+    Terminal,
+}
+
+struct Player {
+    mode: Mode,
+    /// Minimum time between frames; `None` draws as fast as the solver goes.
+    delay: Option<Duration>,
+    last_frame: Option<Instant>,
+    paused: bool,
+    /// Whether we are on the alternate screen in raw mode and must restore it.
+    screen: bool,
+    recorder: Option<Recorder>,
+}
+
+static PLAYER: Mutex<Player> = Mutex::new(Player {
+    mode: Mode::Off,
+    delay: None,
+    last_frame: None,
+    paused: false,
+    screen: false,
+    recorder: None,
+});
+
+fn player() -> std::sync::MutexGuard<'static, Player> {
+    PLAYER
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Choose how frames are drawn. `fps` paces the frames; the terminal view
+/// defaults to [`DEFAULT_FPS`] and the text view to no delay at all.
+pub fn configure(mode: Mode, fps: Option<u32>) {
+    let fps = match mode {
+        Mode::Terminal => Some(fps.unwrap_or(DEFAULT_FPS)),
+        _ => fps,
+    };
+    let mut player = player();
+    player.mode = mode;
+    player.delay = fps
+        .filter(|&fps| fps > 0)
+        .map(|fps| Duration::from_secs(1) / fps);
+    player.paused = false;
+}
+
+/// Keep every frame shown from now on in `recorder`.
+pub fn record(recorder: Recorder) {
+    player().recorder = Some(recorder);
+}
+
+/// Stop recording and write out what was recorded, if anything was being recorded.
+pub fn save_recording() -> Option<io::Result<String>> {
+    let recorder = player().recorder.take()?;
+    Some(recorder.save())
+}
+
+/// Whether frames are being drawn or recorded at all.
+pub fn enabled() -> bool {
+    let player = player();
+    player.mode != Mode::Off || player.recorder.is_some()
+}
+
+/// Draw a frame, then wait for the frame rate or the user's keys.
+pub fn show(frame: &Frame) {
+    let mut player = player();
+    if let Some(recorder) = &mut player.recorder {
+        recorder.push(frame);
+    }
+    let result = match player.mode {
+        Mode::Off => return,
+        Mode::Text => player.show_text(frame),
+        Mode::Terminal => player.show_terminal(frame),
+    };
+    // Nowhere left to draw to, e.g. stderr was closed
+    if result.is_err() {
+        player.leave_screen();
+        player.mode = Mode::Off;
+    }
+}
+
+/// Leave the alternate screen, if we are on it. Call this once the solver is done.
+pub fn finish() {
+    player().leave_screen();
+}
+
+impl Player {
+    fn show_text(&mut self, frame: &Frame) -> io::Result<()> {
+        let mut out = stderr().lock();
+        writeln!(out, "{}", frame.to_plain())?;
+        out.flush()?;
+        if let Some(wait) = self.remaining() {
+            std::thread::sleep(wait);
+        }
+        self.last_frame = Some(Instant::now());
+        Ok(())
+    }
+
+    fn show_terminal(&mut self, frame: &Frame) -> io::Result<()> {
+        if !self.screen && self.enter_screen().is_err() {
+            // Not a terminal after all: fall back to plain text
+            self.leave_screen();
+            self.mode = Mode::Text;
+            return self.show_text(frame);
+        }
+
+        let mut out = stderr().lock();
+        out.queue(cursor::MoveTo(0, 0))?;
+        out.queue(terminal::Clear(terminal::ClearType::All))?;
+        for line in frame.caption.lines() {
+            write!(out, "{}\r\n", line)?;
+        }
+        for row in frame.cells.rows() {
+            for cell in row {
+                match cell.color {
+                    Some(color) => {
+                        out.queue(SetForegroundColor(color))?;
+                        out.queue(Print(cell.glyph))?;
+                        out.queue(ResetColor)?;
+                    }
+                    None => {
+                        out.queue(Print(cell.glyph))?;
+                    }
+                }
+            }
+            write!(out, "\r\n")?;
+        }
+        let state = if self.paused { "paused" } else { "playing" };
+        write!(
+            out,
+            "[{}] space: pause  n: step  +/-: speed  q: stop",
+            state
+        )?;
+        out.flush()?;
+        drop(out);
+
+        self.wait_for_next()?;
+        self.last_frame = Some(Instant::now());
+        Ok(())
+    }
+
+    fn enter_screen(&mut self) -> io::Result<()> {
+        install_panic_hook();
+        terminal::enable_raw_mode()?;
+        self.screen = true;
+        let mut out = stderr();
+        out.queue(terminal::EnterAlternateScreen)?;
+        out.queue(cursor::Hide)?;
+        out.flush()
+    }
+
+    fn leave_screen(&mut self) {
+        if !self.screen {
+            return;
+        }
+        self.screen = false;
+        let mut out = stderr();
+        let _ = out.queue(cursor::Show);
+        let _ = out.queue(terminal::LeaveAlternateScreen);
+        let _ = out.flush();
+        let _ = terminal::disable_raw_mode();
+    }
+
+    /// How much longer the current frame should stay up.
+    fn remaining(&self) -> Option<Duration> {
+        let (delay, last) = (self.delay?, self.last_frame?);
+        delay.checked_sub(last.elapsed())
+    }
+
+    /// Handle keys until the frame has been up long enough, or the user steps.
+    fn wait_for_next(&mut self) -> io::Result<()> {
+        loop {
+            let timeout = if self.paused {
+                Duration::from_millis(100)
+            } else {
+                match self.remaining() {
+                    Some(wait) if !wait.is_zero() => wait,
+                    _ => Duration::ZERO,
+                }
+            };
+
+            if !event::poll(timeout)? {
+                if self.paused {
+                    continue;
+                }
+                return Ok(());
+            }
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press && self.handle_key(key) {
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    /// React to a key. Returns true when the next frame should be drawn now.
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                // Raw mode swallows Ctrl-C, so do what it would have done
+                self.leave_screen();
+                std::process::exit(130);
+            }
+            KeyCode::Char(' ') => {
+                self.paused = !self.paused;
+                false
+            }
+            KeyCode::Char('n') | KeyCode::Right => self.paused,
+            KeyCode::Char('+') => {
+                self.delay = self
+                    .delay
+                    .map(|delay| (delay / 2).max(Duration::from_millis(1)));
+                false
+            }
+            KeyCode::Char('-') => {
+                self.delay = self.delay.map(|delay| delay * 2);
+                false
+            }
+            KeyCode::Char('q') | KeyCode::Esc => {
+                self.leave_screen();
+                self.mode = Mode::Off;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Restore the terminal before a panic message is printed, so it is readable.
+fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let default = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            // The panic may have happened while drawing, with the player locked
+            if let Ok(mut player) = PLAYER.try_lock() {
+                player.leave_screen();
+            }
+            default(info);
+        }));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<char> {
+        Grid::parse_chars("#.#\n...\n", "#.").unwrap()
+    }
+
+    #[test]
+    fn plain_text_has_caption_and_rows() {
+        let frame = Frame::chars(&sample()).caption("step 1");
+        assert_eq!(frame.to_plain(), "step 1\n#.#\n...\n");
+        assert_eq!(Frame::chars(&sample()).to_plain(), "#.#\n...\n");
+    }
+
+    #[test]
+    fn overlays_colour_and_mark_cells() {
+        let path = [Point::new(0, 1), Point::new(1, 1), Point::new(5, 5)];
+        let frame = Frame::new(&sample(), |&ch| match ch {
+            '#' => Cell::from((ch, Color::Blue)),
+            _ => Cell::from(ch),
+        })
+        .color(&[Point::new(1, 0)], Color::Red)
+        .mark(&path, 'o', Color::Yellow);
+
+        let cells = frame.cells();
+        assert_eq!(cells[Point::new(0, 0)].color, Some(Color::Blue));
+        assert_eq!(cells[Point::new(1, 0)], Cell::from(('.', Color::Red)));
+        assert_eq!(cells[Point::new(1, 1)], Cell::from(('o', Color::Yellow)));
+        assert_eq!(cells[Point::new(2, 1)], Cell::from('.'));
+        assert_eq!(frame.to_plain(), "#.#\noo.\n");
+    }
+
+    #[test]
+    fn palette_wraps() {
+        assert_eq!(palette(0), palette(PALETTE.len()));
+        assert_ne!(palette(0), palette(1));
+    }
+}